@@ -1,29 +1,7 @@
-use crate::{to::json::JsonMode, value::TypeToValue, Array, Number, Object, StringB, Value};
-use std::{
-    collections::{BTreeMap, HashMap},
-    fmt::{Display, Formatter},
-};
-
-impl Display for Value {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Value::String(_) => write!(f, "{}", self.to_json(JsonMode::Indented)),
-            Value::Number(value) => write!(f, "{}", value),
-            Value::Boolean(value) => write!(f, "{}", if *value { "true" } else { "false" }),
-            Value::Array(_) => write!(f, "{}", self.to_json(JsonMode::Indented)),
-            Value::Object(_) => write!(f, "{}", self.to_json(JsonMode::Indented)),
-            Value::Null => write!(f, "null"),
-            Value::Undefined => write!(f, "undefined"),
-            Value::DateTime(value) => write!(f, "{}", value),
-        }
-    }
-}
+use crate::{value::TypeToValue, Array, Number, Object, StringB, Value};
+use std::collections::{BTreeMap, HashMap};
 
-impl Default for Value {
-    fn default() -> Self {
-        Value::Null
-    }
-}
+// `Display` and `Default` for `Value` live in `value.rs`, alongside the enum itself.
 
 impl From<BTreeMap<String, Value>> for Value {
     fn from(value: BTreeMap<String, Value>) -> Self {
@@ -175,6 +153,11 @@ impl From<Number> for Value {
     }
 }
 
+// `rust_decimal::Decimal` converts to `Value` through `ToValueBehavior` in `value.rs`
+// (`Value::Decimal`, the dedicated variant), not a `Number`-backed `From` impl here — the two
+// would conflict, since the blanket `impl<T: ToValueBehavior> From<T> for Value` already
+// covers it.
+
 impl From<bool> for Value {
     fn from(value: bool) -> Self {
         Value::Boolean(value)
@@ -0,0 +1,89 @@
+//! Typed extraction out of a `Value`, the inverse of `ToValueBehavior`. Unlike
+//! `FromValueTrait` (which `#[derive(FromValue)]` implements to rebuild a whole struct or
+//! enum, panicking on a shape mismatch), `FromValue` is for pulling a single field out of a `Value`
+//! fallibly — the building block behind `Object::get_as`.
+use crate::prelude::*;
+use std::fmt::{Display, Formatter};
+
+/// The `Value` held was not the variant (or width) the caller asked for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FromValueError {
+    pub expected: &'static str,
+    pub found: Value,
+}
+
+impl Display for FromValueError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected {}, found {:?}", self.expected, self.found)
+    }
+}
+
+impl std::error::Error for FromValueError {}
+
+fn mismatch<T>(expected: &'static str, value: &Value) -> Result<T, FromValueError> {
+    Err(FromValueError {
+        expected,
+        found: value.clone(),
+    })
+}
+
+/// Converts a `&Value` into a concrete Rust type, failing if the `Value` holds a different
+/// variant (or a `Number` of the wrong width) than `T` expects.
+pub trait FromValue: Sized {
+    fn from_value(value: &Value) -> Result<Self, FromValueError>;
+}
+
+impl FromValue for StringB {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::String(s) => Ok(s.clone()),
+            _ => mismatch("String", value),
+        }
+    }
+}
+
+impl FromValue for String {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::String(s) => Ok(s.to_string()),
+            _ => mismatch("String", value),
+        }
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Boolean(b) => Ok(*b),
+            _ => mismatch("Boolean", value),
+        }
+    }
+}
+
+macro_rules! impl_from_value_for_number {
+    ($ty:ty, $expected:expr, $getter:ident) => {
+        impl FromValue for $ty {
+            fn from_value(value: &Value) -> Result<Self, FromValueError> {
+                match value {
+                    Value::Number(number) if number.$getter().is_some() => {
+                        Ok(number.$getter().expect("just checked is_some"))
+                    }
+                    _ => mismatch($expected, value),
+                }
+            }
+        }
+    };
+}
+
+impl_from_value_for_number!(i8, "Number(i8)", get_i8);
+impl_from_value_for_number!(i16, "Number(i16)", get_i16);
+impl_from_value_for_number!(i32, "Number(i32)", get_i32);
+impl_from_value_for_number!(i64, "Number(i64)", get_i64);
+impl_from_value_for_number!(i128, "Number(i128)", get_i128);
+impl_from_value_for_number!(u8, "Number(u8)", get_u8);
+impl_from_value_for_number!(u16, "Number(u16)", get_u16);
+impl_from_value_for_number!(u32, "Number(u32)", get_u32);
+impl_from_value_for_number!(u64, "Number(u64)", get_u64);
+impl_from_value_for_number!(u128, "Number(u128)", get_u128);
+impl_from_value_for_number!(f32, "Number(f32)", get_f32);
+impl_from_value_for_number!(f64, "Number(f64)", get_f64);
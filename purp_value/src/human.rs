@@ -0,0 +1,179 @@
+//! Human-facing rendering, alongside the machine-facing `Display`/JSON paths: relative times
+//! and thousands-grouped integers (echoing nushell's use of `chrono-humanize`), plus an
+//! opt-in byte-size formatter for counts built with `Value::filesize`.
+use crate::prelude::*;
+
+impl Value {
+    /// Renders `self` for a human reader rather than a machine: a `DateTime` becomes relative
+    /// text ("3 days ago", "in 2 hours") measured against `DateTime::now()`, and a large
+    /// integer `Number` gets thousands separators. Anything else falls back to `Display`.
+    pub fn to_human(&self) -> String {
+        match self {
+            Value::DateTime(datetime) => {
+                let delta = DateTime::now()
+                    .duration_between(datetime)
+                    .unwrap_or_else(chrono::Duration::zero);
+                humanize_delta(delta)
+            }
+            Value::Number(number) => grouped_integer_string(number).unwrap_or_else(|| self.to_string()),
+            _ => self.to_string(),
+        }
+    }
+
+    /// Wraps a byte count in a `Value::Number`. There's no dedicated "this is a filesize"
+    /// variant, so the byte-ness is purely a convention between the caller and
+    /// `to_human_filesize` — `to_human` itself has no way to tell this apart from any other
+    /// integer `Value`.
+    pub fn filesize(n: u64) -> Value {
+        Value::Number(Number::from(n))
+    }
+
+    /// Renders `self` (typically built with `Value::filesize`) as `1.5 KiB`, `3.2 MiB`, and
+    /// so on, dividing by powers of 1024. Returns `None` unless `self` is a `Number` holding a
+    /// `u64`, the width `Value::filesize` stores.
+    pub fn to_human_filesize(&self) -> Option<String> {
+        self.get_u64().map(format_filesize)
+    }
+}
+
+/// Buckets a signed duration into the coarsest unit it still reads naturally in — seconds
+/// collapse to "just now", then minutes, hours, days, months (30-day), and years (365-day) —
+/// and phrases it as "ago" for a negative (past) delta or "in ..." for a positive (future) one.
+fn humanize_delta(delta: chrono::Duration) -> String {
+    // Round to the nearest second: `to_human` measures against a `now()` taken a moment after
+    // the caller's, so truncating would read a target 3 hours out as "in 2 hours".
+    let millis = delta.num_milliseconds();
+    let seconds = (millis + millis.signum() * 500) / 1000;
+    let is_future = seconds > 0;
+    let seconds_abs = seconds.unsigned_abs();
+
+    if seconds_abs < 60 {
+        return "just now".to_string();
+    }
+
+    const MINUTE: u64 = 60;
+    const HOUR: u64 = 60 * MINUTE;
+    const DAY: u64 = 24 * HOUR;
+    const MONTH: u64 = 30 * DAY;
+    const YEAR: u64 = 365 * DAY;
+
+    let (amount, unit) = if seconds_abs < HOUR {
+        (seconds_abs / MINUTE, "minute")
+    } else if seconds_abs < DAY {
+        (seconds_abs / HOUR, "hour")
+    } else if seconds_abs < MONTH {
+        (seconds_abs / DAY, "day")
+    } else if seconds_abs < YEAR {
+        (seconds_abs / MONTH, "month")
+    } else {
+        (seconds_abs / YEAR, "year")
+    };
+
+    let plural = if amount == 1 { "" } else { "s" };
+    if is_future {
+        format!("in {} {}{}", amount, unit, plural)
+    } else {
+        format!("{} {}{} ago", amount, unit, plural)
+    }
+}
+
+/// Renders an integer `Number` with `,`-grouped thousands (`"1,234,567"`), or `None` for a
+/// float/decimal/unset `Number`, which `to_human` falls back to `Display` for instead.
+fn grouped_integer_string(number: &Number) -> Option<String> {
+    let magnitude: u128 = match number.number_type() {
+        NumberType::I8 => number.get_i8_unsafe().unsigned_abs() as u128,
+        NumberType::I16 => number.get_i16_unsafe().unsigned_abs() as u128,
+        NumberType::I32 => number.get_i32_unsafe().unsigned_abs() as u128,
+        NumberType::I64 => number.get_i64_unsafe().unsigned_abs() as u128,
+        NumberType::I128 => number.get_i128_unsafe().unsigned_abs(),
+        NumberType::U8 => number.get_u8_unsafe() as u128,
+        NumberType::U16 => number.get_u16_unsafe() as u128,
+        NumberType::U32 => number.get_u32_unsafe() as u128,
+        NumberType::U64 => number.get_u64_unsafe() as u128,
+        NumberType::U128 => number.get_u128_unsafe(),
+        NumberType::F32 | NumberType::F64 => return None,
+        #[cfg(feature = "decimal")]
+        NumberType::Decimal => return None,
+        NumberType::Unknown => return None,
+    };
+
+    let digits = magnitude.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, digit) in digits.chars().rev().enumerate() {
+        if index > 0 && index % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    let grouped: String = grouped.chars().rev().collect();
+    Some(if number.is_negative() {
+        format!("-{}", grouped)
+    } else {
+        grouped
+    })
+}
+
+const FILESIZE_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Formats `bytes` as `1.5 KiB`, `3.2 MiB`, ..., dividing by 1024 while the value is at least
+/// 1024, and capping at `EiB` rather than growing the unit list forever.
+fn format_filesize(bytes: u64) -> String {
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64;
+    let mut unit_index = 0;
+    while value >= 1024.0 && unit_index < FILESIZE_UNITS.len() - 1 {
+        value /= 1024.0;
+        unit_index += 1;
+    }
+    format!("{:.1} {}", value, FILESIZE_UNITS[unit_index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_human_past_datetime_reads_as_ago() {
+        let two_days_ago = DateTime::now().subtract_duration(chrono::Duration::days(2)).unwrap();
+        let human = Value::DateTime(two_days_ago).to_human();
+        assert_eq!(human, "2 days ago");
+    }
+
+    #[test]
+    fn test_to_human_future_datetime_reads_as_in() {
+        let in_three_hours = DateTime::now().add_duration(chrono::Duration::hours(3)).unwrap();
+        let human = Value::DateTime(in_three_hours).to_human();
+        assert_eq!(human, "in 3 hours");
+    }
+
+    #[test]
+    fn test_to_human_large_integer_gets_thousands_separators() {
+        assert_eq!(Value::from(1_234_567i64).to_human(), "1,234,567");
+    }
+
+    #[test]
+    fn test_to_human_negative_integer_keeps_sign_outside_the_grouping() {
+        assert_eq!(Value::from(-1_234i32).to_human(), "-1,234");
+    }
+
+    #[test]
+    fn test_to_human_falls_back_to_display_for_non_number_non_datetime() {
+        assert_eq!(Value::from("hi").to_human(), Value::from("hi").to_string());
+    }
+
+    #[test]
+    fn test_filesize_formats_kib_and_mib() {
+        assert_eq!(Value::filesize(1536).to_human_filesize().unwrap(), "1.5 KiB");
+        assert_eq!(
+            Value::filesize(3_355_443).to_human_filesize().unwrap(),
+            "3.2 MiB"
+        );
+    }
+
+    #[test]
+    fn test_filesize_rejects_non_number() {
+        assert!(Value::from("nope").to_human_filesize().is_none());
+    }
+}
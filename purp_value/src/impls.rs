@@ -75,6 +75,12 @@ impl Value {
             _ => todo!(),
         }
     }
+
+    /// Tolerantly parses a human-entered date/time like `"5 April 2023"` or `"April 5, 2023
+    /// 8pm"` into a `Value::DateTime`. See `DateTime::parse_fuzzy` for the resolution rules.
+    pub fn parse_datetime(input: &str) -> Option<Value> {
+        DateTime::parse_fuzzy(input).map(Value::DateTime)
+    }
 }
 
 impl NumberBehavior for Value {
@@ -0,0 +1,81 @@
+//! Multi-format load/dump support for `Value`, layered on the `serde::Serialize`/`Deserialize`
+//! impls in `value.rs`, `types/object.rs`, and `types/value_key.rs` so a `Value` tree can be
+//! produced from, or rendered to, JSON, YAML, and CBOR.
+use crate::prelude::*;
+use std::fmt::{self, Display, Formatter};
+
+/// An error loading or dumping a `Value` tree through one of the supported wire formats.
+#[derive(Debug)]
+pub enum Error {
+    Json(serde_json::Error),
+    Yaml(serde_yaml::Error),
+    Cbor(serde_cbor::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Json(err) => write!(f, "JSON error: {}", err),
+            Error::Yaml(err) => write!(f, "YAML error: {}", err),
+            Error::Cbor(err) => write!(f, "CBOR error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<serde_json::Error> for Error {
+    fn from(value: serde_json::Error) -> Self {
+        Error::Json(value)
+    }
+}
+
+impl From<serde_yaml::Error> for Error {
+    fn from(value: serde_yaml::Error) -> Self {
+        Error::Yaml(value)
+    }
+}
+
+impl From<serde_cbor::Error> for Error {
+    fn from(value: serde_cbor::Error) -> Self {
+        Error::Cbor(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Value {
+    /// Parses a `Value` tree from a JSON document.
+    pub fn from_json(bytes: &[u8]) -> Result<Value, Error> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    /// Parses a `Value` tree from a YAML document.
+    pub fn from_yaml(bytes: &[u8]) -> Result<Value, Error> {
+        Ok(serde_yaml::from_slice(bytes)?)
+    }
+
+    /// Parses a `Value` tree from a CBOR document.
+    pub fn from_cbor(bytes: &[u8]) -> Result<Value, Error> {
+        Ok(serde_cbor::from_slice(bytes)?)
+    }
+
+    /// Renders `self` as a JSON document through `serde`. Named `dump_json` rather than
+    /// `to_json` to avoid clashing with the hand-rolled, infallible `Value::to_json(JsonMode)`
+    /// writer this type already has.
+    pub fn dump_json(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// Renders `self` as a YAML document through `serde`. Named `dump_yaml` rather than
+    /// `to_yaml` for the same reason as [`Value::dump_json`].
+    pub fn dump_yaml(&self) -> Result<String, Error> {
+        Ok(serde_yaml::to_string(self)?)
+    }
+
+    /// Renders `self` as CBOR through `serde`.
+    pub fn dump_cbor(&self) -> Result<Vec<u8>, Error> {
+        let mut bytes = Vec::new();
+        serde_cbor::to_writer(&mut bytes, self)?;
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,156 @@
+//! A total order over `Value`, so a `Value::Array` can be sorted or a `Value` used as a map
+//! key. Variants are ranked into a stable, cross-variant order first; values of the same
+//! variant then compare meaningfully against each other. Numbers borrow nushell's
+//! `OrderedFloat` trick — comparing through `f64::total_cmp` — so NaN sorts as the largest
+//! value instead of making the comparison panic or fall back to `None`.
+use crate::prelude::*;
+use std::cmp::Ordering;
+
+/// `Value`'s derived `PartialEq` compares `Number`'s float fields with `==`, so a `Value`
+/// holding NaN is not equal to itself; this impl asserts `Eq` regardless and adds no stronger
+/// guarantee.
+impl Eq for Value {}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        variant_rank(self).cmp(&variant_rank(other)).then_with(|| match (self, other) {
+            (Value::Null, Value::Null) => Ordering::Equal,
+            (Value::Undefined, Value::Undefined) => Ordering::Equal,
+            (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
+            (Value::Number(a), Value::Number(b)) => number_as_f64(a).total_cmp(&number_as_f64(b)),
+            (Value::DateTime(a), Value::DateTime(b)) => a.to_iso8601().cmp(&b.to_iso8601()),
+            (Value::String(a), Value::String(b)) => a.as_str().cmp(b.as_str()),
+            (Value::Array(a), Value::Array(b)) => a.iter().cmp(b.iter()),
+            (Value::Object(a), Value::Object(b)) => sorted_entries(a).cmp(&sorted_entries(b)),
+            #[cfg(feature = "bytes")]
+            (Value::Bytes(a), Value::Bytes(b)) => a.cmp(b),
+            #[cfg(feature = "uuid")]
+            (Value::Uuid(a), Value::Uuid(b)) => a.cmp(b),
+            #[cfg(feature = "decimal")]
+            (Value::Decimal(a), Value::Decimal(b)) => a.cmp(b),
+            // Unreachable: `variant_rank` already separated any pair that reaches here
+            // by variant, so same-rank pairs are always also same-variant pairs.
+            _ => Ordering::Equal,
+        })
+    }
+}
+
+/// A stable, cross-variant order: `Null < Boolean < Number < Decimal < DateTime < String
+/// < Uuid < Array < Bytes < Object < Undefined`. Feature-gated variants slot in next to the
+/// variant they're most alike (`Decimal` by `Number`, `Uuid` by `String`, `Bytes` by `Array`).
+fn variant_rank(value: &Value) -> u8 {
+    match value {
+        Value::Null => 0,
+        Value::Boolean(_) => 1,
+        Value::Number(_) => 2,
+        #[cfg(feature = "decimal")]
+        Value::Decimal(_) => 3,
+        Value::DateTime(_) => 4,
+        Value::String(_) => 5,
+        #[cfg(feature = "uuid")]
+        Value::Uuid(_) => 6,
+        Value::Array(_) => 7,
+        #[cfg(feature = "bytes")]
+        Value::Bytes(_) => 8,
+        Value::Object(_) => 9,
+        Value::Undefined => 10,
+    }
+}
+
+/// Reduces a `Number` to an `f64` for comparison: exact for integer widths up to 32 bits and
+/// for floats, approximate for 64/128-bit integers and `Decimal` beyond `f64`'s 53-bit
+/// mantissa, and NaN for a `Number` with nothing set. A NaN is returned with its sign bit
+/// cleared, so `total_cmp` ranks every NaN above `+inf`.
+fn number_as_f64(number: &Number) -> f64 {
+    #[cfg(feature = "decimal")]
+    if let Some(decimal) = &number.decimal {
+        use rust_decimal::prelude::ToPrimitive;
+        return decimal.to_f64().unwrap_or(f64::NAN);
+    }
+    let value = match number.number_type() {
+        NumberType::I8 => number.get_i8_unsafe() as f64,
+        NumberType::I16 => number.get_i16_unsafe() as f64,
+        NumberType::I32 => number.get_i32_unsafe() as f64,
+        NumberType::I64 => number.get_i64_unsafe() as f64,
+        NumberType::I128 => number.get_i128_unsafe() as f64,
+        NumberType::U8 => number.get_u8_unsafe() as f64,
+        NumberType::U16 => number.get_u16_unsafe() as f64,
+        NumberType::U32 => number.get_u32_unsafe() as f64,
+        NumberType::U64 => number.get_u64_unsafe() as f64,
+        NumberType::U128 => number.get_u128_unsafe() as f64,
+        NumberType::F32 => number.get_f32_unsafe() as f64,
+        NumberType::F64 => number.get_f64_unsafe(),
+        #[cfg(feature = "decimal")]
+        NumberType::Decimal => unreachable!("Number.decimal handled above"),
+        NumberType::Unknown => f64::NAN,
+    };
+    if value.is_nan() {
+        value.abs()
+    } else {
+        value
+    }
+}
+
+/// Collects an `Object`'s entries sorted by key, giving a deterministic sequence to compare
+/// lexicographically regardless of which `Object` variant (`HashMap`, `BTreeMap`, ...) holds
+/// the entries.
+fn sorted_entries(object: &Object) -> Vec<(ValueKey, Value)> {
+    let mut entries: Vec<(ValueKey, Value)> =
+        object.iter().map(|(key, value)| (key.clone(), value.clone())).collect();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variant_order_crosses_types() {
+        assert!(Value::Null < Value::Boolean(false));
+        assert!(Value::Boolean(true) < Value::from(1));
+        assert!(Value::from(1) < Value::from("a"));
+        assert!(Value::from("a") < Value::Undefined);
+    }
+
+    #[test]
+    fn test_numbers_compare_by_value_across_widths() {
+        assert!(Value::from(1u8) < Value::from(2i64));
+        // Not `==`: `PartialEq` is still the derived, structural comparison (different widths
+        // populate different `Number` fields), but `Ord` compares by numeric value.
+        assert_eq!(Value::from(2i64).cmp(&Value::from(2u8)), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_nan_sorts_as_the_largest_number() {
+        let nan = Value::from(f64::NAN);
+        let finite = Value::from(1.0f64);
+        assert!(finite < nan);
+    }
+
+    #[test]
+    fn test_arrays_compare_lexicographically() {
+        let a: Value = vec![1.to_value(), 2.to_value()].into();
+        let b: Value = vec![1.to_value(), 3.to_value()].into();
+        assert!(a < b);
+    }
+
+    #[test]
+    fn test_array_sort_orders_numbers_ascending() {
+        let mut array = Array::new();
+        array.push(3.to_value());
+        array.push(1.to_value());
+        array.push(2.to_value());
+        array.sort();
+        assert_eq!(
+            array.iter().cloned().collect::<Vec<_>>(),
+            vec![1.to_value(), 2.to_value(), 3.to_value()]
+        );
+    }
+}
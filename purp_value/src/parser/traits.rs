@@ -29,3 +29,9 @@ pub trait ToXmlTrait {
     /// Converts a type into an XML string.
     fn to_xml(&self) -> String;
 }
+
+/// A trait for converting types to TOML strings.
+pub trait ToTomlTrait {
+    /// Converts a type into a TOML string.
+    fn to_toml(&self) -> String;
+}
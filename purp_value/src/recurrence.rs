@@ -0,0 +1,176 @@
+//! A calendar iterator subsystem (inspired by kairos) for lazily materializing a schedule of
+//! `DateTime`s from a base instant and a repeating step, rather than hand-rolling date math.
+use crate::prelude::*;
+use chrono::Duration;
+
+/// Lazily yields `base`, then `base + step`, `base + step + step`, ... stopping after `count`
+/// items, or running unbounded when `count` is `None`. Stops early (without error) if `step`
+/// ever pushes the date out of range, or if `base` is a bare `DateTime::Time`, which has no
+/// date to advance — in that case only `base` itself is yielded. A `DateTime::Date` base
+/// advances by whole days only, as `add_duration` does, so a sub-day `step` yields the same
+/// date again.
+#[derive(Debug, Clone)]
+pub struct Recurrence {
+    pub base: DateTime,
+    pub step: Duration,
+    pub count: Option<usize>,
+}
+
+impl Recurrence {
+    /// Creates a `Recurrence` starting at `base`, advancing by `step` each time, for `count`
+    /// items (or forever, if `None`).
+    pub fn new(base: DateTime, step: Duration, count: Option<usize>) -> Self {
+        Recurrence { base, step, count }
+    }
+
+    /// Wraps this recurrence so only the `DateTime`s matching `predicate` are yielded, e.g.
+    /// `value.recur("daily").unwrap().filter_by(|dt| !is_weekend(dt))`.
+    pub fn filter_by<F>(self, predicate: F) -> FilterRecurrence<Self, F>
+    where
+        F: FnMut(&DateTime) -> bool,
+    {
+        FilterRecurrence::new(self, predicate)
+    }
+}
+
+impl Iterator for Recurrence {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<DateTime> {
+        if self.count == Some(0) {
+            return None;
+        }
+        let current = self.base.clone();
+        if let Some(count) = &mut self.count {
+            *count -= 1;
+        }
+        // Checked counterpart of `add_duration`, whose `+` panics once the result leaves
+        // chrono's range; a `Date` still advances by whole days only.
+        let next_base = match &self.base {
+            DateTime::Date(date) => date
+                .checked_add_signed(Duration::days(self.step.num_days()))
+                .map(DateTime::Date),
+            DateTime::Time(_) => None,
+            DateTime::DateTime(datetime) => {
+                datetime.checked_add_signed(self.step).map(DateTime::DateTime)
+            }
+            DateTime::OffsetDateTime(datetime) => {
+                datetime.checked_add_signed(self.step).map(DateTime::OffsetDateTime)
+            }
+        };
+        match next_base {
+            Some(next_base) => self.base = next_base,
+            None => self.count = Some(0),
+        }
+        Some(current)
+    }
+}
+
+/// Wraps any `DateTime` iterator — typically a `Recurrence` — with a predicate, skipping
+/// items that don't match rather than stopping at the first mismatch.
+pub struct FilterRecurrence<I, F> {
+    iter: I,
+    predicate: F,
+}
+
+impl<I, F> FilterRecurrence<I, F>
+where
+    I: Iterator<Item = DateTime>,
+    F: FnMut(&DateTime) -> bool,
+{
+    pub fn new(iter: I, predicate: F) -> Self {
+        FilterRecurrence { iter, predicate }
+    }
+}
+
+impl<I, F> Iterator for FilterRecurrence<I, F>
+where
+    I: Iterator<Item = DateTime>,
+    F: FnMut(&DateTime) -> bool,
+{
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<DateTime> {
+        for item in self.iter.by_ref() {
+            if (self.predicate)(&item) {
+                return Some(item);
+            }
+        }
+        None
+    }
+}
+
+/// Maps a recurrence spec word (`"secondly"`, `"minutely"`, `"hourly"`, `"daily"`, `"weekly"`)
+/// to the `Duration` it steps by, case-insensitively. Returns `None` for anything else.
+pub fn parse_recurrence_spec(spec: &str) -> Option<Duration> {
+    match spec.to_lowercase().as_str() {
+        "secondly" => Some(Duration::seconds(1)),
+        "minutely" => Some(Duration::minutes(1)),
+        "hourly" => Some(Duration::hours(1)),
+        "daily" => Some(Duration::days(1)),
+        "weekly" => Some(Duration::weeks(1)),
+        _ => None,
+    }
+}
+
+impl Value {
+    /// Builds a `Recurrence` starting at `self` (which must be a `Value::DateTime`), stepping
+    /// by the `Duration` named by `spec` (see `parse_recurrence_spec`). Returns `None` if
+    /// `self` isn't a `DateTime` or `spec` isn't a recognized recurrence word.
+    ///
+    /// ```ignore
+    /// let week = value.recur("daily").unwrap().take(7);
+    /// ```
+    pub fn recur(&self, spec: &str) -> Option<Recurrence> {
+        let base = match self {
+            Value::DateTime(datetime) => datetime.clone(),
+            _ => return None,
+        };
+        let step = parse_recurrence_spec(spec)?;
+        Some(Recurrence::new(base, step, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recurrence_yields_base_first() {
+        let base = DateTime::from_ymd_opt(2023, 4, 5);
+        let mut recurrence = Recurrence::new(base.clone(), Duration::days(1), Some(3));
+        assert_eq!(recurrence.next(), Some(base));
+    }
+
+    #[test]
+    fn test_recurrence_stops_after_count() {
+        let base = DateTime::from_ymd_opt(2023, 4, 5);
+        let recurrence = Recurrence::new(base, Duration::days(1), Some(3));
+        assert_eq!(recurrence.count(), 3);
+    }
+
+    #[test]
+    fn test_recur_materializes_a_week_of_daily_dates() {
+        let value = Value::DateTime(DateTime::from_ymd_opt(2023, 4, 5));
+        let week: Vec<DateTime> = value.recur("daily").unwrap().take(7).collect();
+        assert_eq!(week.len(), 7);
+        assert_eq!(week[0], DateTime::from_ymd_opt(2023, 4, 5));
+        assert_eq!(week[6], DateTime::from_ymd_opt(2023, 4, 11));
+    }
+
+    #[test]
+    fn test_recur_rejects_unknown_spec() {
+        let value = Value::DateTime(DateTime::from_ymd_opt(2023, 4, 5));
+        assert!(value.recur("fortnightly").is_none());
+    }
+
+    #[test]
+    fn test_filter_recurrence_skips_non_matching_items() {
+        let base = DateTime::from_ymd_opt(2023, 4, 5);
+        let recurrence = Recurrence::new(base, Duration::days(1), Some(4));
+        let odd_days: Vec<DateTime> = recurrence
+            .filter_by(|dt| dt.day().is_some_and(|day| day % 2 == 1))
+            .collect();
+        assert_eq!(odd_days.len(), 2);
+    }
+}
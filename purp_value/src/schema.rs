@@ -0,0 +1,502 @@
+//! A `Schema` subsystem for describing and validating the shape of a `Value` tree,
+//! borrowed from the `Schema`/`SchemaIncomplete` design in amadeus-types.
+//!
+//! `Schema` mirrors the `Value` variants so a caller can describe the shape a dynamically-built
+//! `Value` is expected to have — e.g. the shape of a Rust type, produced by `#[derive(Schema)]` —
+//! and check it with [`Value::validate`] before calling `from_value`.
+use crate::prelude::*;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+/// Describes the expected shape of a `Value`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Schema {
+    Bool,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    F32,
+    F64,
+    String,
+    DateTime,
+    /// Matches `Value::Null` and `Value::Undefined`.
+    Null,
+    Array(Box<Schema>),
+    Object(HashMap<String, Schema>),
+    /// A homogeneous array: every element matches the same schema. Produced by
+    /// [`Value::infer_schema`]; validates exactly like [`Schema::Array`].
+    List(Box<Schema>),
+    /// A homogeneous object: every value matches the same schema, regardless of key. Produced by
+    /// [`Value::infer_schema`]; unlike [`Schema::Object`], there's no fixed set of field names.
+    Map { key: Box<Schema>, value: Box<Schema> },
+    Optional(Box<Schema>),
+    /// Matches any of the given schemas, used for tagged enums where each variant has its own shape.
+    Union(Vec<Schema>),
+    /// Matches any `Value` without further constraint.
+    Any,
+}
+
+/// A single mismatch found while validating a `Value` against a `Schema`, with a
+/// JSON-pointer-style path to the offending node (e.g. `/person/age`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaError {
+    pub path: String,
+    pub message: String,
+}
+
+impl Display for SchemaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl Value {
+    /// Walks `self` and `schema` in parallel, collecting every mismatch rather than
+    /// stopping at the first one.
+    pub fn validate(&self, schema: &Schema) -> Result<(), Vec<SchemaError>> {
+        let mut errors = Vec::new();
+        validate_at("", self, schema, &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn validate_at(path: &str, value: &Value, schema: &Schema, errors: &mut Vec<SchemaError>) {
+    match schema {
+        Schema::Any => {}
+        Schema::Optional(inner) => {
+            if !matches!(value, Value::Null | Value::Undefined) {
+                validate_at(path, value, inner, errors);
+            }
+        }
+        Schema::Union(alternatives) => {
+            let matches_any = alternatives
+                .iter()
+                .any(|alternative| value.validate(alternative).is_ok());
+            if !matches_any {
+                errors.push(mismatch(path, "one of the union's variants", value));
+            }
+        }
+        Schema::Bool => {
+            if !matches!(value, Value::Boolean(_)) {
+                errors.push(mismatch(path, "Boolean", value));
+            }
+        }
+        Schema::String => {
+            if !matches!(value, Value::String(_)) {
+                errors.push(mismatch(path, "String", value));
+            }
+        }
+        Schema::DateTime => {
+            if !matches!(value, Value::DateTime(_)) {
+                errors.push(mismatch(path, "DateTime", value));
+            }
+        }
+        Schema::Null => {
+            if !matches!(value, Value::Null | Value::Undefined) {
+                errors.push(mismatch(path, "Null", value));
+            }
+        }
+        Schema::I8 | Schema::I16 | Schema::I32 | Schema::I64 | Schema::I128 | Schema::U8
+        | Schema::U16 | Schema::U32 | Schema::U64 | Schema::U128 | Schema::F32 | Schema::F64 => {
+            match value {
+                Value::Number(number) if number_matches_width(number, schema) => {}
+                _ => errors.push(mismatch(path, "Number", value)),
+            }
+        }
+        Schema::Array(item_schema) => match value {
+            Value::Array(array) => {
+                for (index, item) in array.iter().enumerate() {
+                    validate_at(&format!("{}/{}", path, index), item, item_schema, errors);
+                }
+            }
+            _ => errors.push(mismatch(path, "Array", value)),
+        },
+        Schema::Object(fields) => match value {
+            Value::Object(object) => {
+                for (key, field_schema) in fields {
+                    let field_path = format!("{}/{}", path, key);
+                    match object.get(key) {
+                        Some(field_value) => {
+                            validate_at(&field_path, field_value, field_schema, errors)
+                        }
+                        None if matches!(field_schema, Schema::Optional(_)) => {}
+                        None => errors.push(SchemaError {
+                            path: field_path,
+                            message: "missing required field".to_string(),
+                        }),
+                    }
+                }
+            }
+            _ => errors.push(mismatch(path, "Object", value)),
+        },
+        Schema::List(item_schema) => match value {
+            Value::Array(array) => {
+                for (index, item) in array.iter().enumerate() {
+                    validate_at(&format!("{}/{}", path, index), item, item_schema, errors);
+                }
+            }
+            _ => errors.push(mismatch(path, "Array", value)),
+        },
+        Schema::Map { value: value_schema, .. } => match value {
+            Value::Object(object) => {
+                for (key, field_value) in object.iter() {
+                    validate_at(&format!("{}/{}", path, key), field_value, value_schema, errors);
+                }
+            }
+            _ => errors.push(mismatch(path, "Object", value)),
+        },
+    }
+}
+
+fn number_matches_width(number: &Number, schema: &Schema) -> bool {
+    match schema {
+        Schema::I8 => number.is_i8(),
+        Schema::I16 => number.is_i16(),
+        Schema::I32 => number.is_i32(),
+        Schema::I64 => number.is_i64(),
+        Schema::I128 => number.is_i128(),
+        Schema::U8 => number.is_u8(),
+        Schema::U16 => number.is_u16(),
+        Schema::U32 => number.is_u32(),
+        Schema::U64 => number.is_u64(),
+        Schema::U128 => number.is_u128(),
+        Schema::F32 => number.is_f32(),
+        Schema::F64 => number.is_f64(),
+        _ => false,
+    }
+}
+
+fn mismatch(path: &str, expected: &str, found: &Value) -> SchemaError {
+    SchemaError {
+        path: if path.is_empty() {
+            "/".to_string()
+        } else {
+            path.to_string()
+        },
+        message: format!("expected {}, found {}", expected, value_type_name(found)),
+    }
+}
+
+fn value_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::String(_) => "String",
+        Value::Number(_) => "Number",
+        Value::Boolean(_) => "Boolean",
+        Value::Array(_) => "Array",
+        Value::Object(_) => "Object",
+        Value::Null => "Null",
+        Value::Undefined => "Undefined",
+        Value::DateTime(_) => "DateTime",
+        #[cfg(feature = "bytes")]
+        Value::Bytes(_) => "Bytes",
+        #[cfg(feature = "uuid")]
+        Value::Uuid(_) => "Uuid",
+        #[cfg(feature = "decimal")]
+        Value::Decimal(_) => "Decimal",
+    }
+}
+
+impl Schema {
+    /// Merges two inferred schemas into one that describes both: identical schemas merge to
+    /// themselves, integer widths widen to the smallest width holding both, mixing an integer
+    /// with a float widens to a float, anything merged with `Null` becomes `Optional`, and
+    /// anything else falls back to `Union`.
+    pub fn merge(a: Schema, b: Schema) -> Schema {
+        if a == b {
+            return a;
+        }
+        match (a, b) {
+            (Schema::Null, other) | (other, Schema::Null) => Schema::Optional(Box::new(other)),
+            (Schema::Optional(inner), other) | (other, Schema::Optional(inner)) => {
+                Schema::Optional(Box::new(Schema::merge(*inner, other)))
+            }
+            (a, b) if numeric_rank(&a).is_some() && numeric_rank(&b).is_some() => {
+                merge_numeric(a, b)
+            }
+            (Schema::List(a), Schema::List(b)) => Schema::List(Box::new(Schema::merge(*a, *b))),
+            (
+                Schema::Map { key: ka, value: va },
+                Schema::Map { key: kb, value: vb },
+            ) => Schema::Map {
+                key: Box::new(Schema::merge(*ka, *kb)),
+                value: Box::new(Schema::merge(*va, *vb)),
+            },
+            (Schema::Union(mut variants), other) | (other, Schema::Union(mut variants)) => {
+                if !variants.contains(&other) {
+                    variants.push(other);
+                }
+                Schema::Union(variants)
+            }
+            (a, b) => Schema::Union(vec![a, b]),
+        }
+    }
+}
+
+/// `(is_float, bit_width, is_signed)` for numeric leaf schemas. `None` for non-numeric schemas.
+fn numeric_rank(schema: &Schema) -> Option<(bool, u8, bool)> {
+    match schema {
+        Schema::I8 => Some((false, 8, true)),
+        Schema::U8 => Some((false, 8, false)),
+        Schema::I16 => Some((false, 16, true)),
+        Schema::U16 => Some((false, 16, false)),
+        Schema::I32 => Some((false, 32, true)),
+        Schema::U32 => Some((false, 32, false)),
+        Schema::I64 => Some((false, 64, true)),
+        Schema::U64 => Some((false, 64, false)),
+        Schema::I128 => Some((false, 128, true)),
+        Schema::U128 => Some((false, 128, false)),
+        Schema::F32 => Some((true, 32, true)),
+        Schema::F64 => Some((true, 64, true)),
+        _ => None,
+    }
+}
+
+fn integer_schema(width: u8, signed: bool) -> Schema {
+    match (width, signed) {
+        (8, true) => Schema::I8,
+        (8, false) => Schema::U8,
+        (16, true) => Schema::I16,
+        (16, false) => Schema::U16,
+        (32, true) => Schema::I32,
+        (32, false) => Schema::U32,
+        (64, true) => Schema::I64,
+        (64, false) => Schema::U64,
+        (_, true) => Schema::I128,
+        (_, false) => Schema::U128,
+    }
+}
+
+/// The next-wider signed integer width, the one guaranteed to hold every value of an unsigned
+/// integer at `width` (an unsigned `N`-bit value needs a signed `2N`-bit slot, since the
+/// signed type spends its top bit on the sign). Caps at 128, the widest width this schema
+/// language has.
+fn next_signed_width(width: u8) -> u8 {
+    match width {
+        8 => 16,
+        16 => 32,
+        32 => 64,
+        _ => 128,
+    }
+}
+
+/// Widens two integer widths/signedness to the narrowest schema that can hold both. Same-sign
+/// pairs just take the wider width. A mismatched-sign pair at the *same* width (e.g. `I8` +
+/// `U8`) can't be resolved by picking either operand's width, since neither can represent the
+/// other's full range (`U8`'s 128..255 doesn't fit in `I8`, and `I8`'s negatives don't fit in
+/// `U8`) — it widens to the next signed width instead (`I8` + `U8` -> `I16`).
+fn merge_integer(a_width: u8, a_signed: bool, b_width: u8, b_signed: bool) -> Schema {
+    if a_signed == b_signed {
+        return integer_schema(a_width.max(b_width), a_signed);
+    }
+    let (unsigned_width, signed_width) = if a_signed {
+        (b_width, a_width)
+    } else {
+        (a_width, b_width)
+    };
+    let widened_width = next_signed_width(unsigned_width).max(signed_width);
+    integer_schema(widened_width, true)
+}
+
+fn merge_numeric(a: Schema, b: Schema) -> Schema {
+    let (a_float, a_width, a_signed) = numeric_rank(&a).expect("caller checked both are numeric");
+    let (b_float, b_width, b_signed) = numeric_rank(&b).expect("caller checked both are numeric");
+    match (a_float, b_float) {
+        (false, false) => merge_integer(a_width, a_signed, b_width, b_signed),
+        (true, true) => {
+            if a_width.max(b_width) == 64 {
+                Schema::F64
+            } else {
+                Schema::F32
+            }
+        }
+        (true, false) | (false, true) => Schema::F64,
+    }
+}
+
+/// Merges the schemas produced by `schemas`, falling back to `Schema::Any` when empty.
+fn merge_all(schemas: impl Iterator<Item = Schema>) -> Schema {
+    schemas
+        .fold(None, |acc, schema| {
+            Some(match acc {
+                Some(acc) => Schema::merge(acc, schema),
+                None => schema,
+            })
+        })
+        .unwrap_or(Schema::Any)
+}
+
+fn number_schema(number: &Number) -> Schema {
+    match number.number_type() {
+        NumberType::I8 => Schema::I8,
+        NumberType::I16 => Schema::I16,
+        NumberType::I32 => Schema::I32,
+        NumberType::I64 => Schema::I64,
+        NumberType::I128 => Schema::I128,
+        NumberType::U8 => Schema::U8,
+        NumberType::U16 => Schema::U16,
+        NumberType::U32 => Schema::U32,
+        NumberType::U64 => Schema::U64,
+        NumberType::U128 => Schema::U128,
+        NumberType::F32 => Schema::F32,
+        NumberType::F64 => Schema::F64,
+        #[cfg(feature = "decimal")]
+        NumberType::Decimal => Schema::Any,
+        NumberType::Unknown => Schema::Any,
+    }
+}
+
+impl Value {
+    /// Walks `self` bottom-up and produces a `Schema` describing its structure, modeled on the
+    /// `Schema`/`SchemaIncomplete` split in amadeus-types: a number's schema comes from its
+    /// `number_type()`, an array's schema is the merge of its elements' schemas, and an object's
+    /// schema records the merge of its values' schemas (every key is treated as `Schema::String`).
+    pub fn infer_schema(&self) -> Schema {
+        match self {
+            Value::String(_) => Schema::String,
+            Value::Number(number) => number_schema(number),
+            Value::Boolean(_) => Schema::Bool,
+            Value::DateTime(_) => Schema::DateTime,
+            Value::Null | Value::Undefined => Schema::Null,
+            Value::Array(array) => {
+                Schema::List(Box::new(merge_all(array.iter().map(Value::infer_schema))))
+            }
+            Value::Object(object) => Schema::Map {
+                key: Box::new(Schema::String),
+                value: Box::new(merge_all(object.values().into_iter().map(Value::infer_schema))),
+            },
+            #[cfg(feature = "bytes")]
+            Value::Bytes(_) => Schema::Any,
+            #[cfg(feature = "uuid")]
+            Value::Uuid(_) => Schema::Any,
+            #[cfg(feature = "decimal")]
+            Value::Decimal(_) => Schema::Any,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_reports_type_mismatch_with_path() {
+        let mut object = Object::default();
+        object.insert("age", StringB::from("thirty").to_value());
+        let value = Value::Object(object);
+
+        let mut fields = HashMap::new();
+        fields.insert("age".to_string(), Schema::U32);
+        let schema = Schema::Object(fields);
+
+        let errors = value.validate(&schema).unwrap_err();
+        assert_eq!(errors[0].path, "/age");
+        assert_eq!(errors[0].message, "expected Number, found String");
+    }
+
+    #[test]
+    fn test_validate_missing_required_field() {
+        let value = Value::Object(Object::default());
+
+        let mut fields = HashMap::new();
+        fields.insert("name".to_string(), Schema::String);
+        let schema = Schema::Object(fields);
+
+        let errors = value.validate(&schema).unwrap_err();
+        assert_eq!(errors[0].path, "/name");
+    }
+
+    #[test]
+    fn test_validate_optional_field_may_be_absent() {
+        let value = Value::Object(Object::default());
+
+        let mut fields = HashMap::new();
+        fields.insert(
+            "nickname".to_string(),
+            Schema::Optional(Box::new(Schema::String)),
+        );
+        let schema = Schema::Object(fields);
+
+        assert!(value.validate(&schema).is_ok());
+    }
+
+    #[test]
+    fn test_infer_schema_scalar() {
+        assert_eq!(StringB::from("hi").to_value().infer_schema(), Schema::String);
+        assert_eq!(42u32.to_value().infer_schema(), Schema::U32);
+    }
+
+    #[test]
+    fn test_infer_schema_homogeneous_array_is_list() {
+        let array: Value = vec![1u32.to_value(), 2u32.to_value(), 3u32.to_value()].into();
+        assert_eq!(array.infer_schema(), Schema::List(Box::new(Schema::U32)));
+    }
+
+    #[test]
+    fn test_infer_schema_mixed_integer_widths_widen() {
+        let array: Value = vec![1u8.to_value(), 300u32.to_value()].into();
+        assert_eq!(array.infer_schema(), Schema::List(Box::new(Schema::U32)));
+    }
+
+    #[test]
+    fn test_infer_schema_same_width_signed_and_unsigned_widen_to_wider_signed() {
+        let array: Value = vec![(-1i8).to_value(), 200u8.to_value()].into();
+        assert_eq!(array.infer_schema(), Schema::List(Box::new(Schema::I16)));
+    }
+
+    #[test]
+    fn test_infer_schema_integer_and_float_widen_to_float() {
+        let array: Value = vec![1u32.to_value(), 1.5f64.to_value()].into();
+        assert_eq!(array.infer_schema(), Schema::List(Box::new(Schema::F64)));
+    }
+
+    #[test]
+    fn test_infer_schema_value_and_null_becomes_optional() {
+        let array: Value = vec![1u32.to_value(), Value::Null].into();
+        assert_eq!(
+            array.infer_schema(),
+            Schema::List(Box::new(Schema::Optional(Box::new(Schema::U32))))
+        );
+    }
+
+    #[test]
+    fn test_infer_schema_unrelated_types_become_union() {
+        let array: Value = vec![1u32.to_value(), StringB::from("x").to_value()].into();
+        assert_eq!(
+            array.infer_schema(),
+            Schema::List(Box::new(Schema::Union(vec![Schema::U32, Schema::String])))
+        );
+    }
+
+    #[test]
+    fn test_infer_schema_object_is_map_of_merged_values() {
+        let mut object = Object::default();
+        object.insert("a", 1u32.to_value());
+        object.insert("b", 2u32.to_value());
+        let value = Value::Object(object);
+
+        assert_eq!(
+            value.infer_schema(),
+            Schema::Map {
+                key: Box::new(Schema::String),
+                value: Box::new(Schema::U32),
+            }
+        );
+    }
+
+    #[test]
+    fn test_infer_schema_empty_array_is_any() {
+        let array: Value = Vec::<Value>::new().into();
+        assert_eq!(array.infer_schema(), Schema::List(Box::new(Schema::Any)));
+    }
+}
@@ -0,0 +1,200 @@
+use crate::prelude::*;
+
+impl Value {
+    /// Renders the value as a TOML document.
+    ///
+    /// Top-level `Value::Object`s become a key/value table; nested objects are
+    /// emitted as `[table]` headers (or inline tables when they are small enough
+    /// to fit on one line) and arrays of objects become `[[array-of-tables]]`.
+    /// `DateTime` values are written as TOML's native, unquoted datetime
+    /// primitives rather than as strings.
+    pub fn to_toml(&self) -> String {
+        match self {
+            Value::Object(object) => toml_table(object, &[]),
+            _ => to_toml_inline(self),
+        }
+    }
+}
+
+fn toml_table(object: &Object, path: &[String]) -> String {
+    let mut scalars = String::new();
+    let mut tables = String::new();
+
+    for (key, value) in object.iter() {
+        let key = key.to_string();
+        match value {
+            Value::Object(nested) => {
+                let mut nested_path = path.to_vec();
+                nested_path.push(key.clone());
+                if is_small_object(nested) {
+                    scalars.push_str(&format!("{} = {}\n", toml_key(&key), to_toml_inline(value)));
+                } else {
+                    tables.push_str(&format!("\n[{}]\n", nested_path.join(".")));
+                    tables.push_str(&toml_table(nested, &nested_path));
+                }
+            }
+            Value::Array(array) if is_array_of_tables(array) => {
+                let mut nested_path = path.to_vec();
+                nested_path.push(key.clone());
+                for entry in array.iter() {
+                    if let Value::Object(entry_object) = entry {
+                        tables.push_str(&format!("\n[[{}]]\n", nested_path.join(".")));
+                        tables.push_str(&toml_table(entry_object, &nested_path));
+                    }
+                }
+            }
+            _ => {
+                scalars.push_str(&format!("{} = {}\n", toml_key(&key), to_toml_inline(value)));
+            }
+        }
+    }
+
+    format!("{}{}", scalars, tables)
+}
+
+fn is_small_object(object: &Object) -> bool {
+    object.len() <= 3
+        && object
+            .values()
+            .iter()
+            .all(|value| !matches!(value, Value::Object(_) | Value::Array(_)))
+}
+
+fn is_array_of_tables(array: &Array) -> bool {
+    !array.is_empty()
+        && array
+            .iter()
+            .all(|value| matches!(value, Value::Object(_)))
+}
+
+fn to_toml_inline(value: &Value) -> String {
+    match value {
+        Value::String(s) => toml_quote(s.as_str()),
+        Value::Number(n) => n.to_string(),
+        Value::Boolean(b) => b.to_string(),
+        Value::Null | Value::Undefined => "\"\"".to_string(),
+        Value::DateTime(dt) => toml_datetime(dt),
+        Value::Array(array) => {
+            let items = array
+                .iter()
+                .map(to_toml_inline)
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("[{}]", items)
+        }
+        Value::Object(object) => {
+            let items = object
+                .iter()
+                .map(|(key, value)| format!("{} = {}", toml_key(&key.to_string()), to_toml_inline(value)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{{ {} }}", items)
+        }
+        #[cfg(feature = "bytes")]
+        Value::Bytes(bytes) => toml_quote(&crate::value::encode_base64(bytes)),
+        #[cfg(feature = "uuid")]
+        Value::Uuid(uuid) => toml_quote(&uuid.to_string()),
+        #[cfg(feature = "decimal")]
+        Value::Decimal(decimal) => decimal.to_string(),
+    }
+}
+
+/// Renders a `DateTime` as a bare, unquoted TOML datetime primitive.
+fn toml_datetime(dt: &DateTime) -> String {
+    match dt {
+        DateTime::Date(date) => date.format("%Y-%m-%d").to_string(),
+        DateTime::Time(time) => time.format("%H:%M:%S").to_string(),
+        DateTime::DateTime(datetime) => datetime.to_rfc3339(),
+        DateTime::OffsetDateTime(datetime) => datetime.to_rfc3339(),
+    }
+}
+
+fn toml_key(key: &str) -> String {
+    if key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') && !key.is_empty() {
+        key.to_string()
+    } else {
+        toml_quote(key)
+    }
+}
+
+fn toml_quote(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn object_from(pairs: Vec<(&str, Value)>) -> Object {
+        let mut map = BTreeMap::new();
+        for (key, value) in pairs {
+            map.insert(key.to_string(), value);
+        }
+        Object::from(map)
+    }
+
+    #[test]
+    fn test_small_object_renders_inline() {
+        let inner = object_from(vec![("x", 1.to_value()), ("y", 2.to_value())]);
+        let outer = object_from(vec![("inner", Value::Object(inner))]);
+
+        assert_eq!(
+            Value::Object(outer).to_toml(),
+            "inner = { x = 1, y = 2 }\n"
+        );
+    }
+
+    #[test]
+    fn test_large_object_renders_as_table_header() {
+        let inner = object_from(vec![
+            ("a", 1.to_value()),
+            ("b", 2.to_value()),
+            ("c", 3.to_value()),
+            ("d", 4.to_value()),
+        ]);
+        let outer = object_from(vec![("inner", Value::Object(inner))]);
+
+        assert_eq!(
+            Value::Object(outer).to_toml(),
+            "\n[inner]\na = 1\nb = 2\nc = 3\nd = 4\n"
+        );
+    }
+
+    #[test]
+    fn test_array_of_objects_renders_as_array_of_tables() {
+        let first = object_from(vec![("name", "first".to_value())]);
+        let second = object_from(vec![("name", "second".to_value())]);
+        let outer = object_from(vec![(
+            "items",
+            vec![Value::Object(first), Value::Object(second)].into(),
+        )]);
+
+        assert_eq!(
+            Value::Object(outer).to_toml(),
+            "\n[[items]]\nname = \"first\"\n\n[[items]]\nname = \"second\"\n"
+        );
+    }
+
+    #[test]
+    fn test_key_and_value_quoting_escapes_special_characters() {
+        let outer = object_from(vec![("my key", "He said \"hi\"".to_value())]);
+
+        assert_eq!(
+            Value::Object(outer).to_toml(),
+            "\"my key\" = \"He said \\\"hi\\\"\"\n"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap};
 use std::iter::Iterator;
 
@@ -31,14 +32,26 @@ pub trait ObjectBehavior {
     fn is_empty(&self) -> bool;
 }
 
-/// An enum representing a JSON object as a `BTreeMap` or a `HashMap`.
+/// An enum representing a JSON object as a `BTreeMap`, a `HashMap`, a `Sorted` map ordered by
+/// a caller-supplied comparator, or — behind the `preserve_order` feature — an
+/// insertion-order-preserving `IndexMap`.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Object {
     BTreeMap(BTreeMap<ValueKey, Value>),
     HashMap(HashMap<ValueKey, Value>),
+    Sorted(SortedObject),
+    #[cfg(feature = "preserve_order")]
+    IndexMap(indexmap::IndexMap<ValueKey, Value>),
 }
 
 impl Object {
+    /// Creates an empty `Object` whose keys are kept sorted by `compare` rather than by
+    /// `ValueKey`'s `Ord` impl, useful for canonical/deterministic output with a
+    /// domain-specific key ordering (e.g. case-insensitive, numeric-aware, or reversed).
+    pub fn ordered_by(compare: fn(&ValueKey, &ValueKey) -> Ordering) -> Self {
+        Object::Sorted(SortedObject::new(compare))
+    }
+
     /// Returns a reference to the value associated with the specified key, or `None` if the key is not present.
     pub fn get<T>(&self, key: &T) -> Option<&Value>
     where
@@ -48,6 +61,9 @@ impl Object {
         match self {
             Object::BTreeMap(map) => map.get(&value_key),
             Object::HashMap(map) => map.get(&value_key),
+            Object::Sorted(map) => map.get(&value_key),
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => map.get(&value_key),
         }
     }
 
@@ -56,6 +72,129 @@ impl Object {
         match self {
             Object::BTreeMap(map) => map.clear(),
             Object::HashMap(map) => map.clear(),
+            Object::Sorted(map) => map.clear(),
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => map.clear(),
+        }
+    }
+
+    /// Returns a mutable reference to the value associated with the specified key, or `None`
+    /// if the key is not present.
+    pub fn get_mut<T>(&mut self, key: &T) -> Option<&mut Value>
+    where
+        T: Into<ValueKey> + Clone,
+    {
+        let value_key: ValueKey = key.clone().into();
+        match self {
+            Object::BTreeMap(map) => map.get_mut(&value_key),
+            Object::HashMap(map) => map.get_mut(&value_key),
+            Object::Sorted(map) => map.get_mut(&value_key),
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => map.get_mut(&value_key),
+        }
+    }
+
+    /// Gets the given key's corresponding entry for in-place accumulation, backed directly by
+    /// the underlying map's own `Entry` API so looking it up costs a single hash/search instead
+    /// of the get-then-insert dance `insert` alone forces on callers.
+    pub fn entry<T: Into<ValueKey>>(&mut self, key: T) -> Entry<'_> {
+        let key: ValueKey = key.into();
+        match self {
+            Object::BTreeMap(map) => Entry::BTreeMap(map.entry(key)),
+            Object::HashMap(map) => Entry::HashMap(map.entry(key)),
+            Object::Sorted(map) => Entry::Sorted(map.entry(key)),
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => Entry::IndexMap(map.entry(key)),
+        }
+    }
+
+    /// Looks up `key` and converts the held `Value` into `T`, the way a caller might pull a
+    /// single known field out of a bag of otherwise-dynamic JSON data. Returns `None` if `key`
+    /// is absent, `Some(Err(_))` if it's present but isn't the variant/width `T` expects.
+    pub fn get_as<T, K>(&self, key: K) -> Option<Result<T, FromValueError>>
+    where
+        T: FromValue,
+        K: Into<ValueKey> + Clone,
+    {
+        self.get(&key).map(T::from_value)
+    }
+
+    /// Looks up `key` and, if present, applies `f` to the held `Value`.
+    pub fn get_with<T, F, K>(&self, key: K, f: F) -> Option<T>
+    where
+        F: FnOnce(&Value) -> T,
+        K: Into<ValueKey> + Clone,
+    {
+        self.get(&key).map(f)
+    }
+
+    /// Converts the whole object into a concrete type `T`, the way a caller might promote a
+    /// bag of fields captured during a partial parse into its final, fully-typed form.
+    #[cfg(feature = "serde")]
+    pub fn deserialize_into<T>(self) -> Result<T, crate::io::Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let json = serde_json::to_value(Value::Object(self))?;
+        Ok(serde_json::from_value(json)?)
+    }
+}
+
+/// A view into a single entry in an `Object`, wrapping whichever backend map produced it so
+/// `or_insert`/`or_insert_with`/`and_modify` delegate straight to that map's own `Entry` type
+/// rather than re-deriving occupied/vacant state from a separate lookup.
+pub enum Entry<'a> {
+    BTreeMap(std::collections::btree_map::Entry<'a, ValueKey, Value>),
+    HashMap(std::collections::hash_map::Entry<'a, ValueKey, Value>),
+    Sorted(SortedEntry<'a>),
+    #[cfg(feature = "preserve_order")]
+    IndexMap(indexmap::map::Entry<'a, ValueKey, Value>),
+}
+
+impl<'a> Entry<'a> {
+    /// Returns a reference to this entry's key.
+    pub fn key(&self) -> &ValueKey {
+        match self {
+            Entry::BTreeMap(entry) => entry.key(),
+            Entry::HashMap(entry) => entry.key(),
+            Entry::Sorted(entry) => entry.key(),
+            #[cfg(feature = "preserve_order")]
+            Entry::IndexMap(entry) => entry.key(),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting `default` if it was vacant, then
+    /// returns a mutable reference to the value.
+    pub fn or_insert(self, default: Value) -> &'a mut Value {
+        match self {
+            Entry::BTreeMap(entry) => entry.or_insert(default),
+            Entry::HashMap(entry) => entry.or_insert(default),
+            Entry::Sorted(entry) => entry.or_insert(default),
+            #[cfg(feature = "preserve_order")]
+            Entry::IndexMap(entry) => entry.or_insert(default),
+        }
+    }
+
+    /// Like `or_insert`, but the default value is computed lazily only if the entry was vacant.
+    pub fn or_insert_with<F: FnOnce() -> Value>(self, default: F) -> &'a mut Value {
+        match self {
+            Entry::BTreeMap(entry) => entry.or_insert_with(default),
+            Entry::HashMap(entry) => entry.or_insert_with(default),
+            Entry::Sorted(entry) => entry.or_insert_with(default),
+            #[cfg(feature = "preserve_order")]
+            Entry::IndexMap(entry) => entry.or_insert_with(default),
+        }
+    }
+
+    /// Applies `f` to the value in place if the entry is occupied, then returns the entry
+    /// unchanged so it can be chained into `or_insert`/`or_insert_with`.
+    pub fn and_modify<F: FnOnce(&mut Value)>(self, f: F) -> Self {
+        match self {
+            Entry::BTreeMap(entry) => Entry::BTreeMap(entry.and_modify(f)),
+            Entry::HashMap(entry) => Entry::HashMap(entry.and_modify(f)),
+            Entry::Sorted(entry) => Entry::Sorted(entry.and_modify(f)),
+            #[cfg(feature = "preserve_order")]
+            Entry::IndexMap(entry) => Entry::IndexMap(entry.and_modify(f)),
         }
     }
 }
@@ -69,6 +208,9 @@ impl ObjectBehavior for Object {
         match self {
             Object::BTreeMap(map) => map.insert(key, value),
             Object::HashMap(map) => map.insert(key, value),
+            Object::Sorted(map) => map.insert(key, value),
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => map.insert(key, value),
         }
     }
 
@@ -80,6 +222,9 @@ impl ObjectBehavior for Object {
         match self {
             Object::BTreeMap(map) => map.remove(&key),
             Object::HashMap(map) => map.remove(&key),
+            Object::Sorted(map) => map.remove(&key),
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => map.shift_remove(&key),
         }
     }
 
@@ -91,6 +236,9 @@ impl ObjectBehavior for Object {
         match self {
             Object::BTreeMap(map) => map.contains_key(&key),
             Object::HashMap(map) => map.contains_key(&key),
+            Object::Sorted(map) => map.contains_key(&key),
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => map.contains_key(&key),
         }
     }
 
@@ -98,6 +246,9 @@ impl ObjectBehavior for Object {
         match self {
             Object::BTreeMap(map) => map.keys().collect(),
             Object::HashMap(map) => map.keys().collect(),
+            Object::Sorted(map) => map.keys(),
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => map.keys().collect(),
         }
     }
 
@@ -105,6 +256,9 @@ impl ObjectBehavior for Object {
         match self {
             Object::BTreeMap(map) => map.values().collect(),
             Object::HashMap(map) => map.values().collect(),
+            Object::Sorted(map) => map.values(),
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => map.values().collect(),
         }
     }
 
@@ -112,6 +266,9 @@ impl ObjectBehavior for Object {
         match self {
             Object::BTreeMap(map) => map.len(),
             Object::HashMap(map) => map.len(),
+            Object::Sorted(map) => map.len(),
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => map.len(),
         }
     }
 
@@ -119,14 +276,25 @@ impl ObjectBehavior for Object {
         match self {
             Object::BTreeMap(map) => map.is_empty(),
             Object::HashMap(map) => map.is_empty(),
+            Object::Sorted(map) => map.is_empty(),
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => map.is_empty(),
         }
     }
 }
 
 impl Default for Object {
-    /// Creates a new `Object` with an empty `HashMap`.
+    /// Creates a new, empty `Object`. With the `preserve_order` feature enabled this is
+    /// backed by an `IndexMap` so field order survives round-trips; otherwise it's a `HashMap`.
     fn default() -> Self {
-        Object::HashMap(HashMap::new())
+        #[cfg(feature = "preserve_order")]
+        {
+            Object::IndexMap(indexmap::IndexMap::new())
+        }
+        #[cfg(not(feature = "preserve_order"))]
+        {
+            Object::HashMap(HashMap::new())
+        }
     }
 }
 
@@ -182,19 +350,17 @@ impl From<HashMap<ValueKey, Value>> for Object {
 }
 
 impl From<Vec<(ValueKey, Value)>> for Object {
-    /// Converts a vector of key-value pairs into an Object.
+    /// Converts a vector of key-value pairs into an Object, preferring the order-preserving
+    /// variant when the `preserve_order` feature is enabled so deserialized objects stay order-stable.
     fn from(value: Vec<(ValueKey, Value)>) -> Self {
-        Object::HashMap(value.into_iter().collect())
-    }
-}
-
-impl<T> From<Vec<(ValueKey, Value)>> for Object 
-where
-    T: Into<ValueKey> + Clone + ValueKeyBehavior,
-    {
-    /// Converts a vector of key-value pairs into an Object.
-    fn from(value: Vec<(ValueKey, Value)>) -> Self {
-        Object::HashMap(value.into_iter().collect())
+        #[cfg(feature = "preserve_order")]
+        {
+            Object::IndexMap(value.into_iter().collect())
+        }
+        #[cfg(not(feature = "preserve_order"))]
+        {
+            Object::HashMap(value.into_iter().collect())
+        }
     }
 }
 
@@ -204,6 +370,9 @@ impl Into<HashMap<ValueKey, Value>> for Object {
         match self {
             Object::BTreeMap(map) => map.into_iter().collect(),
             Object::HashMap(map) => map,
+            Object::Sorted(map) => map.into_iter().collect(),
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => map.into_iter().collect(),
         }
     }
 }
@@ -214,6 +383,9 @@ impl Into<BTreeMap<ValueKey, Value>> for Object {
         match self {
             Object::BTreeMap(map) => map,
             Object::HashMap(map) => map.into_iter().collect(),
+            Object::Sorted(map) => map.into_iter().collect(),
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => map.into_iter().collect(),
         }
     }
 }
@@ -228,6 +400,9 @@ pub struct ObjectIter<'a> {
 enum IterState<'a> {
     BTreeMap(std::collections::btree_map::Iter<'a, ValueKey, Value>),
     HashMap(std::collections::hash_map::Iter<'a, ValueKey, Value>),
+    Sorted(std::slice::Iter<'a, (ValueKey, Value)>),
+    #[cfg(feature = "preserve_order")]
+    IndexMap(indexmap::map::Iter<'a, ValueKey, Value>),
 }
 
 impl<'a> Iterator for ObjectIter<'a> {
@@ -237,6 +412,9 @@ impl<'a> Iterator for ObjectIter<'a> {
         match &mut self.state {
             IterState::BTreeMap(iter) => iter.next(),
             IterState::HashMap(iter) => iter.next(),
+            IterState::Sorted(iter) => iter.next().map(|(key, value)| (key, value)),
+            #[cfg(feature = "preserve_order")]
+            IterState::IndexMap(iter) => iter.next(),
         }
     }
 }
@@ -253,7 +431,193 @@ impl<'a> Object {
                 object: self,
                 state: IterState::HashMap(map.iter()),
             },
+
+            Object::Sorted(map) => ObjectIter {
+                object: self,
+                state: IterState::Sorted(map.iter()),
+            },
+
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => ObjectIter {
+                object: self,
+                state: IterState::IndexMap(map.iter()),
+            },
+        }
+    }
+
+    /// Returns an iterator yielding `(&ValueKey, &mut Value)`, letting callers transform
+    /// entries in place without cloning the whole map.
+    pub fn iter_mut(&'a mut self) -> ObjectIterMut<'a> {
+        match self {
+            Object::BTreeMap(map) => ObjectIterMut {
+                state: IterMutState::BTreeMap(map.iter_mut()),
+            },
+            Object::HashMap(map) => ObjectIterMut {
+                state: IterMutState::HashMap(map.iter_mut()),
+            },
+            Object::Sorted(map) => ObjectIterMut {
+                state: IterMutState::Sorted(map.iter_mut()),
+            },
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => ObjectIterMut {
+                state: IterMutState::IndexMap(map.iter_mut()),
+            },
+        }
+    }
+
+    /// Returns a `Vec` of mutable references to the values in the object.
+    pub fn values_mut(&'a mut self) -> Vec<&'a mut Value> {
+        self.iter_mut().map(|(_, value)| value).collect()
+    }
+}
+
+/// A mutable iterator over the key-value pairs in an Object.
+pub struct ObjectIterMut<'a> {
+    state: IterMutState<'a>,
+}
+
+enum IterMutState<'a> {
+    BTreeMap(std::collections::btree_map::IterMut<'a, ValueKey, Value>),
+    HashMap(std::collections::hash_map::IterMut<'a, ValueKey, Value>),
+    Sorted(std::slice::IterMut<'a, (ValueKey, Value)>),
+    #[cfg(feature = "preserve_order")]
+    IndexMap(indexmap::map::IterMut<'a, ValueKey, Value>),
+}
+
+impl<'a> Iterator for ObjectIterMut<'a> {
+    type Item = (&'a ValueKey, &'a mut Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.state {
+            IterMutState::BTreeMap(iter) => iter.next(),
+            IterMutState::HashMap(iter) => iter.next(),
+            IterMutState::Sorted(iter) => iter.next().map(|(key, value)| (&*key, value)),
+            #[cfg(feature = "preserve_order")]
+            IterMutState::IndexMap(iter) => iter.next(),
+        }
+    }
+}
+
+/// An owning iterator over the key-value pairs in an Object.
+pub struct ObjectIntoIter {
+    state: IntoIterState,
+}
+
+enum IntoIterState {
+    BTreeMap(std::collections::btree_map::IntoIter<ValueKey, Value>),
+    HashMap(std::collections::hash_map::IntoIter<ValueKey, Value>),
+    Sorted(std::vec::IntoIter<(ValueKey, Value)>),
+    #[cfg(feature = "preserve_order")]
+    IndexMap(indexmap::map::IntoIter<ValueKey, Value>),
+}
+
+impl Iterator for ObjectIntoIter {
+    type Item = (ValueKey, Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.state {
+            IntoIterState::BTreeMap(iter) => iter.next(),
+            IntoIterState::HashMap(iter) => iter.next(),
+            IntoIterState::Sorted(iter) => iter.next(),
+            #[cfg(feature = "preserve_order")]
+            IntoIterState::IndexMap(iter) => iter.next(),
+        }
+    }
+}
+
+impl IntoIterator for Object {
+    type Item = (ValueKey, Value);
+    type IntoIter = ObjectIntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            Object::BTreeMap(map) => ObjectIntoIter {
+                state: IntoIterState::BTreeMap(map.into_iter()),
+            },
+            Object::HashMap(map) => ObjectIntoIter {
+                state: IntoIterState::HashMap(map.into_iter()),
+            },
+            Object::Sorted(map) => ObjectIntoIter {
+                state: IntoIterState::Sorted(map.into_iter()),
+            },
+            #[cfg(feature = "preserve_order")]
+            Object::IndexMap(map) => ObjectIntoIter {
+                state: IntoIterState::IndexMap(map.into_iter()),
+            },
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Object {
+    type Item = (&'a ValueKey, &'a Value);
+    type IntoIter = ObjectIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Object {
+    type Item = (&'a ValueKey, &'a mut Value);
+    type IntoIter = ObjectIterMut<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl FromIterator<(ValueKey, Value)> for Object {
+    fn from_iter<I: IntoIterator<Item = (ValueKey, Value)>>(iter: I) -> Self {
+        let mut object = Object::default();
+        for (key, value) in iter {
+            object.insert(key, value);
+        }
+        object
+    }
+}
+
+/// Serializes `Object` as a wire-format map, in whichever order `iter()` yields entries
+/// (insertion order for the `IndexMap` variant, otherwise the backing map's own order).
+#[cfg(feature = "serde")]
+impl serde::Serialize for Object {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(ObjectBehavior::len(self)))?;
+        for (key, value) in self.iter() {
+            map.serialize_entry(&key.to_string(), value)?;
+        }
+        map.end()
+    }
+}
+
+/// Deserializes `Object` from a wire-format map. The resulting variant is whatever
+/// `Object::default()` picks, so this respects the `preserve_order` feature automatically.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Object {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ObjectVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ObjectVisitor {
+            type Value = Object;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a map")
+            }
+
+            fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut object = Object::default();
+                while let Some((key, value)) = access.next_entry::<String, Value>()? {
+                    object.insert(key, value);
+                }
+                Ok(object)
+            }
         }
+
+        deserializer.deserialize_map(ObjectVisitor)
     }
 }
 
@@ -285,6 +649,101 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_entry_or_insert_then_and_modify() {
+        let mut obj = Object::default();
+
+        obj.entry("count").or_insert(0.to_value());
+        obj.entry("count")
+            .and_modify(|v| {
+                let next = v.get_i32().unwrap_or(0) + 1;
+                *v = next.to_value();
+            })
+            .or_insert(0.to_value());
+
+        assert_eq!(obj.get("count"), Some(&1.to_value()));
+    }
+
+    #[test]
+    fn test_iter_mut_transforms_values_in_place() {
+        let mut map = BTreeMap::new();
+        map.insert("a".to_string(), 1.to_value());
+        map.insert("b".to_string(), 2.to_value());
+        let mut obj = Object::from(map);
+
+        for (_, value) in obj.iter_mut() {
+            *value = (value.get_i32().unwrap_or(0) * 10).to_value();
+        }
+
+        assert_eq!(obj.get("a"), Some(&10.to_value()));
+        assert_eq!(obj.get("b"), Some(&20.to_value()));
+    }
+
+    #[test]
+    fn test_into_iter_consumes_object() {
+        let mut map = BTreeMap::new();
+        map.insert("a".to_string(), 1.to_value());
+        let obj = Object::from(map);
+
+        let collected: Vec<(ValueKey, Value)> = obj.into_iter().collect();
+        assert_eq!(collected.len(), 1);
+    }
+
+    #[test]
+    fn test_ordered_by_keeps_custom_comparator_order() {
+        let mut obj = Object::ordered_by(|a, b| b.to_string().cmp(&a.to_string()));
+
+        obj.insert("alpha", 1.to_value());
+        obj.insert("charlie", 3.to_value());
+        obj.insert("bravo", 2.to_value());
+
+        let keys: Vec<String> = obj.iter().map(|(key, _)| key.to_string()).collect();
+        assert_eq!(keys, vec!["charlie", "bravo", "alpha"]);
+    }
+
+    #[test]
+    fn test_ordered_by_insert_replaces_existing_value() {
+        let mut obj = Object::ordered_by(|a, b| a.to_string().cmp(&b.to_string()));
+
+        obj.insert("key", 1.to_value());
+        let previous = obj.insert("key", 2.to_value());
+
+        assert_eq!(previous, Some(1.to_value()));
+        assert_eq!(obj.get("key"), Some(&2.to_value()));
+    }
+
+    #[test]
+    fn test_get_as_converts_matching_field() {
+        let mut obj = Object::default();
+        obj.insert("name", StringB::from("Ada").to_value());
+
+        let name: String = obj.get_as::<String, _>("name").unwrap().unwrap();
+        assert_eq!(name, "Ada");
+    }
+
+    #[test]
+    fn test_get_as_reports_mismatch() {
+        let mut obj = Object::default();
+        obj.insert("age", StringB::from("thirty").to_value());
+
+        assert!(obj.get_as::<i32, _>("age").unwrap().is_err());
+    }
+
+    #[test]
+    fn test_get_as_missing_key_is_none() {
+        let obj = Object::default();
+        assert!(obj.get_as::<String, _>("missing").is_none());
+    }
+
+    #[test]
+    fn test_get_with_applies_closure_to_value() {
+        let mut obj = Object::default();
+        obj.insert("count", 3.to_value());
+
+        let doubled = obj.get_with("count", |value| value.get_i32().unwrap_or(0) * 2);
+        assert_eq!(doubled, Some(6));
+    }
+
     #[test]
     fn test_object_from_vec() {
         let vec = vec![
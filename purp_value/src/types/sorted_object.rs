@@ -0,0 +1,186 @@
+use crate::prelude::*;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+
+/// A `ValueKey`-to-`Value` map whose order is governed by an explicit comparator rather than
+/// `ValueKey`'s own `Ord` impl, the way the `copse` crate threads a comparator through a
+/// B-tree instead of relying on the key type's trait impl. The comparator is fixed at
+/// construction and used for every insert, lookup, and removal, so iteration order stays
+/// stable for the lifetime of the map.
+///
+/// `compare` is a plain function pointer rather than a boxed closure: unlike `Rc<dyn Fn(..)>`,
+/// a `fn` pointer is `Send`/`Sync`, so a `SortedObject` (and the `Object`/`Value` it backs)
+/// stays usable across threads like every other `Object` variant.
+#[derive(Clone)]
+pub struct SortedObject {
+    entries: Vec<(ValueKey, Value)>,
+    compare: fn(&ValueKey, &ValueKey) -> Ordering,
+}
+
+impl SortedObject {
+    /// Creates a new, empty `SortedObject` ordered by `compare`.
+    pub fn new(compare: fn(&ValueKey, &ValueKey) -> Ordering) -> Self {
+        SortedObject {
+            entries: Vec::new(),
+            compare,
+        }
+    }
+
+    fn search(&self, key: &ValueKey) -> Result<usize, usize> {
+        self.entries
+            .binary_search_by(|(existing, _)| (self.compare)(existing, key))
+    }
+
+    pub fn insert(&mut self, key: ValueKey, value: Value) -> Option<Value> {
+        match self.search(&key) {
+            Ok(index) => Some(std::mem::replace(&mut self.entries[index].1, value)),
+            Err(index) => {
+                self.entries.insert(index, (key, value));
+                None
+            }
+        }
+    }
+
+    /// Gets `key`'s entry with a single `search`, mirroring `BTreeMap::entry`/`HashMap::entry`
+    /// for the other `Object` backends instead of looking `key` up twice.
+    pub fn entry(&mut self, key: ValueKey) -> SortedEntry<'_> {
+        match self.search(&key) {
+            Ok(index) => SortedEntry::Occupied {
+                object: self,
+                index,
+            },
+            Err(index) => SortedEntry::Vacant {
+                object: self,
+                key,
+                index,
+            },
+        }
+    }
+
+    pub fn get(&self, key: &ValueKey) -> Option<&Value> {
+        self.search(key).ok().map(|index| &self.entries[index].1)
+    }
+
+    pub fn get_mut(&mut self, key: &ValueKey) -> Option<&mut Value> {
+        match self.search(key) {
+            Ok(index) => Some(&mut self.entries[index].1),
+            Err(_) => None,
+        }
+    }
+
+    pub fn remove(&mut self, key: &ValueKey) -> Option<Value> {
+        match self.search(key) {
+            Ok(index) => Some(self.entries.remove(index).1),
+            Err(_) => None,
+        }
+    }
+
+    pub fn contains_key(&self, key: &ValueKey) -> bool {
+        self.search(key).is_ok()
+    }
+
+    pub fn keys(&self) -> Vec<&ValueKey> {
+        self.entries.iter().map(|(key, _)| key).collect()
+    }
+
+    pub fn values(&self) -> Vec<&Value> {
+        self.entries.iter().map(|(_, value)| value).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, (ValueKey, Value)> {
+        self.entries.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, (ValueKey, Value)> {
+        self.entries.iter_mut()
+    }
+
+    pub fn into_iter(self) -> std::vec::IntoIter<(ValueKey, Value)> {
+        self.entries.into_iter()
+    }
+}
+
+/// A view into a single `SortedObject` entry, found by the one `search` call in
+/// `SortedObject::entry` rather than a separate `contains_key`-then-`get_mut` pair.
+pub enum SortedEntry<'a> {
+    Occupied {
+        object: &'a mut SortedObject,
+        index: usize,
+    },
+    Vacant {
+        object: &'a mut SortedObject,
+        key: ValueKey,
+        index: usize,
+    },
+}
+
+impl<'a> SortedEntry<'a> {
+    /// Returns a reference to this entry's key.
+    pub fn key(&self) -> &ValueKey {
+        match self {
+            SortedEntry::Occupied { object, index } => &object.entries[*index].0,
+            SortedEntry::Vacant { key, .. } => key,
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting `default` if it was vacant, then
+    /// returns a mutable reference to the value.
+    pub fn or_insert(self, default: Value) -> &'a mut Value {
+        match self {
+            SortedEntry::Occupied { object, index } => &mut object.entries[index].1,
+            SortedEntry::Vacant { object, key, index } => {
+                object.entries.insert(index, (key, default));
+                &mut object.entries[index].1
+            }
+        }
+    }
+
+    /// Like `or_insert`, but the default value is computed lazily only if the entry was vacant.
+    pub fn or_insert_with<F: FnOnce() -> Value>(self, default: F) -> &'a mut Value {
+        match self {
+            SortedEntry::Occupied { object, index } => &mut object.entries[index].1,
+            SortedEntry::Vacant { object, key, index } => {
+                object.entries.insert(index, (key, default()));
+                &mut object.entries[index].1
+            }
+        }
+    }
+
+    /// Applies `f` to the value in place if the entry is occupied, then returns the entry
+    /// unchanged so it can be chained into `or_insert`/`or_insert_with`.
+    pub fn and_modify<F: FnOnce(&mut Value)>(mut self, f: F) -> Self {
+        if let SortedEntry::Occupied { object, index } = &mut self {
+            f(&mut object.entries[*index].1);
+        }
+        self
+    }
+}
+
+/// Compares `entries` only: two `SortedObject`s with the same key-value pairs are equal
+/// regardless of whether they were built with the same comparator function.
+impl PartialEq for SortedObject {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+/// Prints as a map of its entries; the comparator function is left out.
+impl Debug for SortedObject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.entries.iter().map(|(key, value)| (key, value)))
+            .finish()
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::{Display, Formatter};
+
+/// Behavior for types that can be used to index into an `Object` or `Array`: converted into
+/// a canonical `ValueKey` and, when numeric, resolved to a `usize` for array indexing.
+pub trait ValueKeyBehavior {
+    fn to_value_key(&self) -> ValueKey;
+    fn to_usize(&self) -> usize;
+}
+
+/// A canonical object/array key: either a named field or a numeric index.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ValueKey {
+    String(String),
+    Index(usize),
+}
+
+impl ValueKey {
+    pub fn to_usize(&self) -> usize {
+        match self {
+            ValueKey::String(s) => s.parse().unwrap_or(0),
+            ValueKey::Index(i) => *i,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            ValueKey::String(s) => Some(s),
+            ValueKey::Index(_) => None,
+        }
+    }
+}
+
+impl ValueKeyBehavior for ValueKey {
+    fn to_value_key(&self) -> ValueKey {
+        self.clone()
+    }
+
+    fn to_usize(&self) -> usize {
+        ValueKey::to_usize(self)
+    }
+}
+
+impl Display for ValueKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValueKey::String(s) => write!(f, "{}", s),
+            ValueKey::Index(i) => write!(f, "{}", i),
+        }
+    }
+}
+
+impl From<String> for ValueKey {
+    fn from(value: String) -> Self {
+        ValueKey::String(value)
+    }
+}
+
+impl From<&str> for ValueKey {
+    fn from(value: &str) -> Self {
+        ValueKey::String(value.to_string())
+    }
+}
+
+impl From<usize> for ValueKey {
+    fn from(value: usize) -> Self {
+        ValueKey::Index(value)
+    }
+}
+
+impl ValueKeyBehavior for String {
+    fn to_value_key(&self) -> ValueKey {
+        ValueKey::String(self.clone())
+    }
+
+    fn to_usize(&self) -> usize {
+        self.parse().unwrap_or(0)
+    }
+}
+
+impl ValueKeyBehavior for &str {
+    fn to_value_key(&self) -> ValueKey {
+        ValueKey::String(self.to_string())
+    }
+
+    fn to_usize(&self) -> usize {
+        self.parse().unwrap_or(0)
+    }
+}
+
+impl ValueKeyBehavior for usize {
+    fn to_value_key(&self) -> ValueKey {
+        ValueKey::Index(*self)
+    }
+
+    fn to_usize(&self) -> usize {
+        *self
+    }
+}
+
+/// Serializes a `ValueKey` as a plain string, since every supported wire format (JSON, YAML,
+/// CBOR) represents map keys as strings.
+#[cfg(feature = "serde")]
+impl serde::Serialize for ValueKey {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Deserializes a `ValueKey` as a `ValueKey::String`; the distinction between a named field
+/// and a numeric index only matters for keys built in Rust, not ones read off the wire.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ValueKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(ValueKey::String(value))
+    }
+}
@@ -19,6 +19,8 @@ use crate::prelude::*;
 use std::collections::{BTreeMap, HashMap};
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Represents different data types as an enum.
 #[derive(Debug, Clone, PartialEq)]
@@ -31,6 +33,12 @@ pub enum Value {
     Null,
     Undefined,
     DateTime(DateTime),
+    #[cfg(feature = "bytes")]
+    Bytes(Vec<u8>),
+    #[cfg(feature = "uuid")]
+    Uuid(uuid::Uuid),
+    #[cfg(feature = "decimal")]
+    Decimal(rust_decimal::Decimal),
 }
 
 impl Default for Value {
@@ -41,6 +49,81 @@ impl Default for Value {
 
 impl ValueTrait for Value {}
 
+/// Lets a caller pull the raw bytes back out of a `Value::Bytes`, the way `NumberBehavior`
+/// and `DateTimeBehavior` expose the payload of their variants.
+#[cfg(feature = "bytes")]
+pub trait BinaryBehavior {
+    fn as_bytes(&self) -> Option<&[u8]>;
+}
+
+#[cfg(feature = "bytes")]
+impl BinaryBehavior for Value {
+    fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Value::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+}
+
+/// Lets a caller pull the `uuid::Uuid` back out of a `Value::Uuid`.
+#[cfg(feature = "uuid")]
+pub trait UuidBehavior {
+    fn as_uuid(&self) -> Option<&uuid::Uuid>;
+}
+
+#[cfg(feature = "uuid")]
+impl UuidBehavior for Value {
+    fn as_uuid(&self) -> Option<&uuid::Uuid> {
+        match self {
+            Value::Uuid(uuid) => Some(uuid),
+            _ => None,
+        }
+    }
+}
+
+/// Lets a caller pull the `rust_decimal::Decimal` back out of a `Value::Decimal`, the
+/// arbitrary-precision alternative to `Number`'s `f64` path.
+#[cfg(feature = "decimal")]
+pub trait DecimalBehavior {
+    fn as_decimal(&self) -> Option<&rust_decimal::Decimal>;
+}
+
+#[cfg(feature = "decimal")]
+impl DecimalBehavior for Value {
+    fn as_decimal(&self) -> Option<&rust_decimal::Decimal> {
+        match self {
+            Value::Decimal(decimal) => Some(decimal),
+            _ => None,
+        }
+    }
+}
+
+/// Wraps `bytes` in a `Value::Bytes`. A dedicated constructor rather than a `ToValueBehavior`
+/// impl for `Vec<u8>`, since the blanket `impl<T: ToValueBehavior> ToValueBehavior for Vec<T>`
+/// already covers `Vec<u8>` (producing a `Value::Array` of `Value::Number`s) and Rust's
+/// coherence rules don't allow a second, overlapping impl for that same concrete type.
+#[cfg(feature = "bytes")]
+impl Value {
+    pub fn from_bytes(bytes: Vec<u8>) -> Value {
+        Value::Bytes(bytes)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl ToValueBehavior for uuid::Uuid {
+    fn to_value(&self) -> Value {
+        Value::Uuid(*self)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl ToValueBehavior for rust_decimal::Decimal {
+    fn to_value(&self) -> Value {
+        Value::Decimal(*self)
+    }
+}
+
 impl ToValueBehavior for u8 {
     fn to_value(&self) -> Value {
         Value::Number(Number::from(*self))
@@ -181,10 +264,24 @@ impl Display for Value {
             Value::Null => write!(f, "null"),
             Value::Undefined => write!(f, "undefined"),
             Value::DateTime(value) => write!(f, "{}", value),
+            #[cfg(feature = "bytes")]
+            Value::Bytes(bytes) => write!(f, "{}", encode_base64(bytes)),
+            #[cfg(feature = "uuid")]
+            Value::Uuid(uuid) => write!(f, "{}", uuid),
+            #[cfg(feature = "decimal")]
+            Value::Decimal(decimal) => write!(f, "{}", decimal),
         }
     }
 }
 
+/// Renders bytes the way `Value::Bytes`'s `Display` impl does: as standard (non-URL-safe)
+/// base64, matching how most JSON/YAML consumers expect binary data to round-trip as text.
+#[cfg(feature = "bytes")]
+pub(crate) fn encode_base64(bytes: &[u8]) -> String {
+    use base64::Engine;
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
 impl From<()> for Value {
     fn from(_: ()) -> Self {
         Value::Null
@@ -221,6 +318,155 @@ impl ToValueBehavior for Vec<Value> {
     }
 }
 
+/// Serializes `Value` the way its variant would naturally render in JSON: scalars as
+/// themselves, `Array`/`Object` as a seq/map, and `Null`/`Undefined` both collapsing to the
+/// wire format's unit/null representation (formats like JSON have no "undefined").
+#[cfg(feature = "serde")]
+impl serde::Serialize for Value {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Value::String(value) => serializer.serialize_str(value.as_str()),
+            Value::Number(value) => serialize_number(value, serializer),
+            Value::Boolean(value) => serializer.serialize_bool(*value),
+            Value::Array(array) => {
+                use serde::ser::SerializeSeq;
+                let mut seq = serializer.serialize_seq(Some(array.len()))?;
+                for item in array.iter() {
+                    seq.serialize_element(item)?;
+                }
+                seq.end()
+            }
+            Value::Object(object) => object.serialize(serializer),
+            Value::Null | Value::Undefined => serializer.serialize_unit(),
+            Value::DateTime(value) => serializer.serialize_str(&value.to_string()),
+            #[cfg(feature = "bytes")]
+            Value::Bytes(bytes) => serializer.serialize_bytes(bytes),
+            #[cfg(feature = "uuid")]
+            Value::Uuid(uuid) => serializer.serialize_str(&uuid.to_string()),
+            #[cfg(feature = "decimal")]
+            Value::Decimal(decimal) => serializer.serialize_str(&decimal.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_number<S: serde::Serializer>(
+    number: &Number,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    #[cfg(feature = "decimal")]
+    if let Some(decimal) = &number.decimal {
+        return serializer.serialize_str(&decimal.to_string());
+    }
+    match number.number_type() {
+        NumberType::I8 => serializer.serialize_i8(number.get_i8_unsafe()),
+        NumberType::I16 => serializer.serialize_i16(number.get_i16_unsafe()),
+        NumberType::I32 => serializer.serialize_i32(number.get_i32_unsafe()),
+        NumberType::I64 => serializer.serialize_i64(number.get_i64_unsafe()),
+        NumberType::I128 => serializer.serialize_i128(number.get_i128_unsafe()),
+        NumberType::U8 => serializer.serialize_u8(number.get_u8_unsafe()),
+        NumberType::U16 => serializer.serialize_u16(number.get_u16_unsafe()),
+        NumberType::U32 => serializer.serialize_u32(number.get_u32_unsafe()),
+        NumberType::U64 => serializer.serialize_u64(number.get_u64_unsafe()),
+        NumberType::U128 => serializer.serialize_u128(number.get_u128_unsafe()),
+        NumberType::F32 => serializer.serialize_f32(number.get_f32_unsafe()),
+        NumberType::F64 => serializer.serialize_f64(number.get_f64_unsafe()),
+        #[cfg(feature = "decimal")]
+        NumberType::Decimal => unreachable!("Number.decimal handled above"),
+        NumberType::Unknown => serializer.serialize_unit(),
+    }
+}
+
+/// Deserializes `Value` from any self-describing format (JSON, YAML, CBOR, ...), inferring
+/// the variant from the wire shape rather than from a schema.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Value {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ValueVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ValueVisitor {
+            type Value = Value;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("a JSON-style value")
+            }
+
+            fn visit_bool<E>(self, value: bool) -> Result<Value, E> {
+                Ok(Value::Boolean(value))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Value, E> {
+                Ok(Value::Number(Number {
+                    i64: Some(value),
+                    ..Default::default()
+                }))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Value, E> {
+                Ok(Value::Number(Number {
+                    u64: Some(value),
+                    ..Default::default()
+                }))
+            }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Value, E> {
+                Ok(Value::Number(Number {
+                    f64: Some(value),
+                    ..Default::default()
+                }))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(Value::String(StringB::new(value.to_string())))
+            }
+
+            fn visit_string<E>(self, value: String) -> Result<Value, E> {
+                Ok(Value::String(StringB::new(value)))
+            }
+
+            fn visit_unit<E>(self) -> Result<Value, E> {
+                Ok(Value::Null)
+            }
+
+            fn visit_none<E>(self) -> Result<Value, E> {
+                Ok(Value::Null)
+            }
+
+            fn visit_some<D>(self, deserializer: D) -> Result<Value, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                Value::deserialize(deserializer)
+            }
+
+            fn visit_seq<A>(self, mut access: A) -> Result<Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut array = Array::new();
+                while let Some(item) = access.next_element::<Value>()? {
+                    array.push(item);
+                }
+                Ok(Value::Array(array))
+            }
+
+            fn visit_map<A>(self, access: A) -> Result<Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let object =
+                    Object::deserialize(serde::de::value::MapAccessDeserializer::new(access))?;
+                Ok(Value::Object(object))
+            }
+        }
+
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -284,4 +530,30 @@ mod tests {
         let value = Value::DateTime(datetime.clone());
         assert_eq!(value, Value::DateTime(datetime));
     }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn test_value_bytes() {
+        let value = Value::from_bytes(vec![1, 2, 3]);
+        assert_eq!(value.as_bytes(), Some(&[1u8, 2, 3][..]));
+        assert_eq!(value.to_string(), "AQID");
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_value_uuid() {
+        let uuid = uuid::Uuid::nil();
+        let value = uuid.to_value();
+        assert_eq!(value.as_uuid(), Some(&uuid));
+        assert_eq!(value.to_string(), "00000000-0000-0000-0000-000000000000");
+    }
+
+    #[test]
+    #[cfg(feature = "decimal")]
+    fn test_value_decimal() {
+        let decimal = rust_decimal::Decimal::new(12345, 2);
+        let value = decimal.to_value();
+        assert_eq!(value.as_decimal(), Some(&decimal));
+        assert_eq!(value.to_string(), "123.45");
+    }
 }
@@ -0,0 +1,82 @@
+use crate::value::Value;
+
+/// A dynamically-typed JSON-style array: an ordered list of `Value`s.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Array(Vec<Value>);
+
+impl Array {
+    /// Creates a new, empty `Array`.
+    pub fn new() -> Self {
+        Array(Vec::new())
+    }
+
+    /// Returns a reference to the value at `index`, or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<&Value> {
+        self.0.get(index)
+    }
+
+    /// Returns a mutable reference to the value at `index`, or `None` if out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Value> {
+        self.0.get_mut(index)
+    }
+
+    /// No-op hook mirroring `Number::clean`/`Object::clear`, kept for symmetry with the
+    /// other container types that `Value::clean` delegates to.
+    pub fn clean(&mut self) {}
+
+    /// Returns the number of elements in the array.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the array contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Appends `value` to the end of the array.
+    pub fn push(&mut self, value: Value) {
+        self.0.push(value);
+    }
+
+    /// Removes and returns the last value in the array, or `None` if it is empty.
+    pub fn pop(&mut self) -> Option<Value> {
+        self.0.pop()
+    }
+
+    /// Returns an iterator over the array's elements.
+    pub fn iter(&self) -> std::slice::Iter<'_, Value> {
+        self.0.iter()
+    }
+
+    /// Sorts the array in place using `Value`'s total order (see `ord.rs`), giving a
+    /// heterogeneous array a deterministic ordering instead of requiring elements to be the
+    /// same variant.
+    pub fn sort(&mut self) {
+        self.0.sort();
+    }
+}
+
+impl From<Vec<Value>> for Array {
+    fn from(value: Vec<Value>) -> Self {
+        Array(value)
+    }
+}
+
+impl IntoIterator for Array {
+    type Item = Value;
+    type IntoIter = std::vec::IntoIter<Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Array {
+    type Item = &'a Value;
+    type IntoIter = std::slice::Iter<'a, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
@@ -1,7 +1,10 @@
 use chrono::{
-    DateTime as ChDateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveTime, Timelike, Utc,
+    DateTime as ChDateTime, Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveTime,
+    TimeZone, Timelike, Utc,
 };
+use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 /// Enum representing a date, time, or date-time value.
 ///
@@ -10,11 +13,14 @@ use std::fmt::{Display, Formatter};
 /// * `Date(NaiveDate)` - Represents a date without timezone information.
 /// * `Time(NaiveTime)` - Represents a time without date and timezone information.
 /// * `DateTime(ChDateTime<chrono::Utc>)` - Represents a date-time with timezone information.
+/// * `OffsetDateTime(ChDateTime<FixedOffset>)` - Represents a date-time with an explicit,
+///   non-UTC offset, such as one parsed from an ISO 8601 string like `2023-04-05T12:34:56+09:00`.
 #[derive(Debug, Clone, PartialEq)]
 pub enum DateTime {
     Date(NaiveDate),
     Time(NaiveTime),
     DateTime(ChDateTime<chrono::Utc>),
+    OffsetDateTime(ChDateTime<FixedOffset>),
 }
 
 // Implementations of From trait to allow conversion from NaiveDate, NaiveTime, and ChDateTime<Utc>
@@ -36,6 +42,12 @@ impl From<ChDateTime<chrono::Utc>> for DateTime {
     }
 }
 
+impl From<ChDateTime<FixedOffset>> for DateTime {
+    fn from(value: ChDateTime<FixedOffset>) -> Self {
+        DateTime::OffsetDateTime(value)
+    }
+}
+
 // Implementations of From trait to allow conversion from LocalResult variants
 impl From<LocalResult<NaiveDate>> for DateTime {
     fn from(value: LocalResult<NaiveDate>) -> Self {
@@ -55,19 +67,90 @@ impl From<LocalResult<ChDateTime<chrono::Utc>>> for DateTime {
     }
 }
 
-// Implementation of From trait to allow conversion from &str
+impl From<LocalResult<ChDateTime<FixedOffset>>> for DateTime {
+    fn from(value: LocalResult<ChDateTime<FixedOffset>>) -> Self {
+        DateTime::OffsetDateTime(value.unwrap())
+    }
+}
+
+/// Error returned when a string cannot be parsed as a `DateTime`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateTimeParseError {
+    input: String,
+}
+
+impl Display for DateTimeParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid date, time, or date-time format: {:?}",
+            self.input
+        )
+    }
+}
+
+impl Error for DateTimeParseError {}
+
+// Implementation of TryFrom trait to allow fallible, round-trippable conversion from &str
+impl TryFrom<&str> for DateTime {
+    type Error = DateTimeParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Ok(datetime) = ChDateTime::<FixedOffset>::parse_from_rfc3339(value) {
+            return Ok(normalize_fixed_offset(datetime));
+        }
+        if let Ok(datetime) = ChDateTime::<FixedOffset>::parse_from_rfc2822(value) {
+            return Ok(normalize_fixed_offset(datetime));
+        }
+        if let Ok(date) = value.parse::<NaiveDate>() {
+            return Ok(DateTime::Date(date));
+        }
+        if let Ok(time) = value.parse::<NaiveTime>() {
+            return Ok(DateTime::Time(time));
+        }
+        if let Ok(datetime) = value.parse::<ChDateTime<chrono::Utc>>() {
+            return Ok(DateTime::DateTime(datetime));
+        }
+        // Accept a single space in place of the `T` separator between date and time,
+        // since that's how many hand-formatted or legacy inputs render a date-time.
+        if let Some((date_part, time_part)) = value.split_once(' ') {
+            let with_t = format!("{}T{}", date_part, time_part);
+            if with_t != value {
+                if let Ok(datetime) = DateTime::try_from(with_t.as_str()) {
+                    return Ok(datetime);
+                }
+            }
+        }
+        Err(DateTimeParseError {
+            input: value.to_string(),
+        })
+    }
+}
+
+/// A parsed offset that's exactly UTC (`+00:00`/`Z`) round-trips as `DateTime::DateTime`
+/// instead of `DateTime::OffsetDateTime`, matching how `DateTime::now()`/`Display` represent a
+/// UTC value — otherwise every UTC instant would come back from `TryFrom<&str>` as the wrong
+/// variant, since `FixedOffset::parse_from_rfc3339` happily accepts a zero offset too.
+fn normalize_fixed_offset(datetime: ChDateTime<FixedOffset>) -> DateTime {
+    if datetime.offset().local_minus_utc() == 0 {
+        DateTime::DateTime(datetime.with_timezone(&Utc))
+    } else {
+        DateTime::OffsetDateTime(datetime)
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = DateTimeParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        DateTime::try_from(value)
+    }
+}
+
+// Implementation of From trait to allow conversion from &str, kept for backward compatibility.
 impl From<&str> for DateTime {
     fn from(value: &str) -> Self {
-        match value.parse::<NaiveDate>() {
-            Ok(date) => DateTime::Date(date),
-            Err(_) => match value.parse::<NaiveTime>() {
-                Ok(time) => DateTime::Time(time),
-                Err(_) => match value.parse::<ChDateTime<chrono::Utc>>() {
-                    Ok(datetime) => DateTime::DateTime(datetime),
-                    Err(_) => panic!("Invalid date, time, or date-time format"),
-                },
-            },
-        }
+        DateTime::try_from(value).expect("Invalid date, time, or date-time format")
     }
 }
 
@@ -78,6 +161,7 @@ impl Display for DateTime {
             DateTime::Date(value) => write!(f, "{}", value),
             DateTime::Time(value) => write!(f, "{}", value),
             DateTime::DateTime(value) => write!(f, "{}", value.to_rfc3339()),
+            DateTime::OffsetDateTime(value) => write!(f, "{}", value.to_rfc3339()),
         }
     }
 }
@@ -105,11 +189,19 @@ impl DateTime {
         }
     }
 
+    pub fn as_offset_date_time(&self) -> Option<&ChDateTime<FixedOffset>> {
+        match self {
+            DateTime::OffsetDateTime(value) => Some(value),
+            _ => None,
+        }
+    }
+
     // DateTime methods for accessing specific components of date or time values
     pub fn year(&self) -> Option<i32> {
         match self {
             DateTime::Date(date) => Some(date.year()),
             DateTime::DateTime(datetime) => Some(datetime.year()),
+            DateTime::OffsetDateTime(datetime) => Some(datetime.year()),
             _ => None,
         }
     }
@@ -118,6 +210,7 @@ impl DateTime {
         match self {
             DateTime::Date(date) => Some(date.month()),
             DateTime::DateTime(datetime) => Some(datetime.month()),
+            DateTime::OffsetDateTime(datetime) => Some(datetime.month()),
             _ => None,
         }
     }
@@ -126,6 +219,7 @@ impl DateTime {
         match self {
             DateTime::Date(date) => Some(date.day()),
             DateTime::DateTime(datetime) => Some(datetime.day()),
+            DateTime::OffsetDateTime(datetime) => Some(datetime.day()),
             _ => None,
         }
     }
@@ -134,6 +228,7 @@ impl DateTime {
         match self {
             DateTime::Time(time) => Some(time.hour()),
             DateTime::DateTime(datetime) => Some(datetime.hour()),
+            DateTime::OffsetDateTime(datetime) => Some(datetime.hour()),
             _ => None,
         }
     }
@@ -142,6 +237,7 @@ impl DateTime {
         match self {
             DateTime::Time(time) => Some(time.minute()),
             DateTime::DateTime(datetime) => Some(datetime.minute()),
+            DateTime::OffsetDateTime(datetime) => Some(datetime.minute()),
             _ => None,
         }
     }
@@ -150,6 +246,7 @@ impl DateTime {
         match self {
             DateTime::Time(time) => Some(time.second()),
             DateTime::DateTime(datetime) => Some(datetime.second()),
+            DateTime::OffsetDateTime(datetime) => Some(datetime.second()),
             _ => None,
         }
     }
@@ -157,6 +254,7 @@ impl DateTime {
     pub fn timestamp(&self) -> Option<i64> {
         match self {
             DateTime::DateTime(datetime) => Some(datetime.timestamp()),
+            DateTime::OffsetDateTime(datetime) => Some(datetime.timestamp()),
             _ => None,
         }
     }
@@ -168,18 +266,31 @@ impl DateTime {
         }
     }
 
+    /// Returns the `FixedOffset` of an `OffsetDateTime`, or `None` for every other variant
+    /// (including `DateTime`, whose offset is always UTC).
+    pub fn offset(&self) -> Option<FixedOffset> {
+        match self {
+            DateTime::OffsetDateTime(datetime) => Some(*datetime.offset()),
+            _ => None,
+        }
+    }
+
     // Methods for formatting DateTime values as strings
     pub fn to_iso8601(&self) -> String {
         match self {
             DateTime::Date(date) => date.format("%Y-%m-%d").to_string(),
             DateTime::Time(time) => time.format("%H:%M:%S%.f").to_string(),
             DateTime::DateTime(datetime) => datetime.format("%Y-%m-%dT%H:%M:%S").to_string(),
+            DateTime::OffsetDateTime(datetime) => {
+                datetime.format("%Y-%m-%dT%H:%M:%S").to_string()
+            }
         }
     }
 
     pub fn to_rfc3339(&self) -> String {
         match self {
             DateTime::DateTime(datetime) => datetime.to_rfc3339(),
+            DateTime::OffsetDateTime(datetime) => datetime.to_rfc3339(),
             _ => "".to_string(),
         }
     }
@@ -192,6 +303,9 @@ impl DateTime {
             )),
             DateTime::Time(_) => None, // Não é possível adicionar duração a um NaiveTime isolado
             DateTime::DateTime(datetime) => Some(DateTime::DateTime(*datetime + duration)),
+            DateTime::OffsetDateTime(datetime) => {
+                Some(DateTime::OffsetDateTime(*datetime + duration))
+            }
         }
     }
 
@@ -202,6 +316,9 @@ impl DateTime {
             )),
             DateTime::Time(_) => None, // Não é possível subtrair duração de um NaiveTime isolado
             DateTime::DateTime(datetime) => Some(DateTime::DateTime(*datetime - duration)),
+            DateTime::OffsetDateTime(datetime) => {
+                Some(DateTime::OffsetDateTime(*datetime - duration))
+            }
         }
     }
 
@@ -212,15 +329,297 @@ impl DateTime {
                 Some(Duration::days((*date2 - *date1).num_days()))
             }
             (DateTime::DateTime(dt1), DateTime::DateTime(dt2)) => Some(*dt2 - *dt1),
+            (DateTime::OffsetDateTime(dt1), DateTime::OffsetDateTime(dt2)) => {
+                Some(dt2.with_timezone(&Utc) - dt1.with_timezone(&Utc))
+            }
+            (DateTime::DateTime(dt1), DateTime::OffsetDateTime(dt2)) => {
+                Some(dt2.with_timezone(&Utc) - *dt1)
+            }
+            (DateTime::OffsetDateTime(dt1), DateTime::DateTime(dt2)) => {
+                Some(*dt2 - dt1.with_timezone(&Utc))
+            }
             _ => None, // Retornar None para combinações inválidas
         }
     }
 }
 
+impl DateTime {
+    /// Builds a `DateTime::Date` from a calendar year/month/day. Named `_opt` to match
+    /// `DateTimeBehavior::from_ymd_opt`, which this backs, even though it panics (rather than
+    /// returning `None`) on an out-of-range date.
+    pub fn from_ymd_opt(year: i32, month: u32, day: u32) -> DateTime {
+        DateTime::Date(NaiveDate::from_ymd_opt(year, month, day).expect("invalid calendar date"))
+    }
+
+    /// Builds a `DateTime::DateTime` from the current instant, backing `DateTimeBehavior::now`.
+    pub fn now() -> DateTime {
+        DateTime::DateTime(Utc::now())
+    }
+}
+
+// Fuzzy, natural-language date/time parsing, modeled on dtparse's tokenize-then-resolve
+// approach: `TryFrom<&str>` above only ever accepts a handful of strict, unambiguous formats.
+impl DateTime {
+    /// Tolerantly parses a human-entered date or date-time, such as `"5 April 2023"`,
+    /// `"2023/4/5 12:34"`, or `"April 5, 2023 8pm"`. Unlike `TryFrom<&str>`, which only accepts
+    /// strict RFC3339/RFC2822/ISO forms, this tokenizes the input and resolves the pieces with
+    /// heuristics, returning `None` if the result would be missing or out-of-range components.
+    pub fn parse_fuzzy(input: &str) -> Option<DateTime> {
+        let tokens = tokenize_fuzzy(input);
+
+        let mut month_from_name = None;
+        let mut is_pm = None;
+        let mut date_numbers = Vec::new();
+        let mut time_numbers = Vec::new();
+
+        let mut index = 0;
+        while index < tokens.len() {
+            match &tokens[index] {
+                FuzzyToken::Word(word) => {
+                    let lower = word.to_lowercase();
+                    if let Some(month) = month_from_name_lookup(&lower) {
+                        month_from_name = Some(month);
+                    } else if lower == "am" {
+                        is_pm = Some(false);
+                    } else if lower == "pm" {
+                        is_pm = Some(true);
+                    }
+                    index += 1;
+                }
+                FuzzyToken::Colon => index += 1,
+                FuzzyToken::Number(value) => {
+                    if tokens.get(index + 1) == Some(&FuzzyToken::Colon) {
+                        // A number followed by `:` starts an hour:minute(:second) time group;
+                        // keep consuming colon-joined numbers into the same group.
+                        time_numbers.push(*value);
+                        index += 2;
+                        while let Some(&FuzzyToken::Number(next)) = tokens.get(index) {
+                            time_numbers.push(next);
+                            index += 1;
+                            if tokens.get(index) == Some(&FuzzyToken::Colon) {
+                                index += 1;
+                            } else {
+                                break;
+                            }
+                        }
+                    } else if matches!(
+                        tokens.get(index + 1),
+                        Some(FuzzyToken::Word(word)) if is_am_or_pm(word)
+                    ) {
+                        // A bare hour directly followed by an am/pm word with no colon (e.g.
+                        // the "8" in "8pm") is still a one-number time group, not a date number.
+                        time_numbers.push(*value);
+                        index += 1;
+                    } else {
+                        date_numbers.push(*value);
+                        index += 1;
+                    }
+                }
+            }
+        }
+
+        let (year, month, day) = resolve_date(&date_numbers, month_from_name)?;
+        let date = NaiveDate::from_ymd_opt(year, month, day)?;
+
+        if time_numbers.is_empty() {
+            return Some(DateTime::Date(date));
+        }
+        let (hour, minute, second) = resolve_time(&time_numbers, is_pm)?;
+        match Utc.with_ymd_and_hms(year, month, day, hour, minute, second) {
+            LocalResult::Single(datetime) => Some(DateTime::DateTime(datetime)),
+            _ => None,
+        }
+    }
+}
+
+/// A token produced by scanning a fuzzy date string: a run of digits, a run of letters, or a
+/// `:` separating hour/minute/second groups. Every other separator (space, comma, slash, dash,
+/// period) is discarded rather than tokenized, since it carries no information beyond marking a
+/// boundary between tokens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FuzzyToken<'a> {
+    Number(u32),
+    Word(&'a str),
+    Colon,
+}
+
+fn tokenize_fuzzy(input: &str) -> Vec<FuzzyToken<'_>> {
+    let mut tokens = Vec::new();
+    let bytes = input.as_bytes();
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if byte.is_ascii_digit() {
+            let start = index;
+            while index < bytes.len() && bytes[index].is_ascii_digit() {
+                index += 1;
+            }
+            if let Ok(value) = input[start..index].parse() {
+                tokens.push(FuzzyToken::Number(value));
+            }
+        } else if byte.is_ascii_alphabetic() {
+            let start = index;
+            while index < bytes.len() && bytes[index].is_ascii_alphabetic() {
+                index += 1;
+            }
+            tokens.push(FuzzyToken::Word(&input[start..index]));
+        } else {
+            if byte == b':' {
+                tokens.push(FuzzyToken::Colon);
+            }
+            index += 1;
+        }
+    }
+    tokens
+}
+
+fn is_am_or_pm(word: &str) -> bool {
+    let lower = word.to_lowercase();
+    lower == "am" || lower == "pm"
+}
+
+fn month_from_name_lookup(lower: &str) -> Option<u32> {
+    let month = match lower {
+        "january" | "jan" => 1,
+        "february" | "feb" => 2,
+        "march" | "mar" => 3,
+        "april" | "apr" => 4,
+        "may" => 5,
+        "june" | "jun" => 6,
+        "july" | "jul" => 7,
+        "august" | "aug" => 8,
+        "september" | "sep" | "sept" => 9,
+        "october" | "oct" => 10,
+        "november" | "nov" => 11,
+        "december" | "dec" => 12,
+        _ => return None,
+    };
+    Some(month)
+}
+
+/// Turns a two-digit year into a four-digit one using the common `69..=99 => 1900s`,
+/// `00..=68 => 2000s` pivot.
+fn normalize_two_digit_year(year: u32) -> i32 {
+    match year {
+        0..=68 => 2000 + year as i32,
+        69..=99 => 1900 + year as i32,
+        _ => year as i32,
+    }
+}
+
+/// Resolves the numeric tokens left over once any month name, AM/PM marker, and colon-joined
+/// time group have been pulled out. `month_from_name` takes priority over the numeric heuristics
+/// below when present.
+fn resolve_date(numbers: &[u32], month_from_name: Option<u32>) -> Option<(i32, u32, u32)> {
+    let mut numbers = numbers.to_vec();
+
+    // A value over 31, or already 4 digits, can only be a year; with no such value and at least
+    // three numbers, fall back to the last one, matching the common trailing-year `"4/5/23"`
+    // ordering. Fewer than three numbers with no year-like value resolve to `None`.
+    let year_index = numbers.iter().position(|&n| n > 31 || n >= 1000);
+    let year_raw = match year_index {
+        Some(index) => numbers.remove(index),
+        None if numbers.len() >= 3 => numbers.remove(numbers.len() - 1),
+        None => return None,
+    };
+    let year = normalize_two_digit_year(year_raw);
+
+    let month = match month_from_name {
+        Some(month) => month,
+        None => {
+            if numbers.is_empty() {
+                return None;
+            }
+            // No month name: the first remaining number that could still be a month (<=12) is
+            // taken as the month; a default month-before-day order breaks a genuine tie.
+            let month_index = numbers.iter().position(|&n| n <= 12).unwrap_or(0);
+            numbers.remove(month_index)
+        }
+    };
+
+    if numbers.is_empty() {
+        return None;
+    }
+    let day = numbers.remove(0);
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    Some((year, month, day))
+}
+
+/// Resolves a colon-joined time group (`hour[:minute[:second]]`) plus an optional AM/PM marker
+/// into 24-hour components.
+fn resolve_time(numbers: &[u32], is_pm: Option<bool>) -> Option<(u32, u32, u32)> {
+    let mut hour = *numbers.first()?;
+    let minute = numbers.get(1).copied().unwrap_or(0);
+    let second = numbers.get(2).copied().unwrap_or(0);
+
+    match is_pm {
+        Some(true) if hour < 12 => hour += 12,
+        Some(false) if hour == 12 => hour = 0,
+        _ => {}
+    }
+
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    Some((hour, minute, second))
+}
+
 #[cfg(test)]
 mod tests {
     use super::DateTime;
-    use chrono::{Duration, NaiveDate, TimeZone, Utc};
+    use chrono::{Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+
+    #[test]
+    fn test_offset_date_time_from_str() {
+        let dt = DateTime::from("2023-04-05T12:34:56+09:00");
+        let offset = FixedOffset::east_opt(9 * 3600).unwrap();
+        assert_eq!(dt.offset(), Some(offset));
+        assert_eq!(dt.hour(), Some(12));
+        assert_eq!(dt.to_rfc3339(), "2023-04-05T12:34:56+09:00");
+    }
+
+    #[test]
+    fn test_duration_between_utc_and_offset() {
+        let utc = DateTime::from(Utc.with_ymd_and_hms(2023, 4, 5, 3, 34, 56));
+        let offset = DateTime::from("2023-04-05T12:34:56+09:00");
+        assert_eq!(utc.duration_between(&offset), Some(Duration::zero()));
+    }
+
+    #[test]
+    fn test_try_from_rejects_garbage_instead_of_panicking() {
+        assert!(DateTime::try_from("not a date").is_err());
+    }
+
+    #[test]
+    fn test_try_from_rfc2822() {
+        let dt = DateTime::try_from("Tue, 1 Jul 2003 10:52:37 +0200").unwrap();
+        assert_eq!(dt.hour(), Some(10));
+        assert_eq!(dt.offset(), Some(FixedOffset::east_opt(2 * 3600).unwrap()));
+    }
+
+    #[test]
+    fn test_try_from_space_separator() {
+        let dt = DateTime::try_from("2023-04-05 12:34:56+09:00").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2023-04-05T12:34:56+09:00");
+    }
+
+    #[test]
+    fn test_display_round_trips_through_from_str() {
+        let dt = DateTime::from("2023-04-05T12:34:56+09:00");
+        let round_tripped: DateTime = dt.to_string().parse().unwrap();
+        assert_eq!(dt, round_tripped);
+    }
+
+    #[test]
+    fn test_utc_datetime_round_trips_as_the_datetime_variant() {
+        let dt = DateTime::from(Utc.with_ymd_and_hms(2023, 4, 5, 12, 34, 56));
+        let round_tripped: DateTime = dt.to_string().parse().unwrap();
+        assert_eq!(dt, round_tripped);
+        assert!(matches!(round_tripped, DateTime::DateTime(_)));
+    }
 
     #[test]
     fn test_add_duration() {
@@ -279,4 +678,38 @@ mod tests {
             Some(Duration::days(1))
         );
     }
+
+    #[test]
+    fn test_parse_fuzzy_day_month_name_year() {
+        let dt = DateTime::parse_fuzzy("5 April 2023").unwrap();
+        assert_eq!(dt, DateTime::from(NaiveDate::from_ymd_opt(2023, 4, 5).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_fuzzy_slash_separated_numeric() {
+        let dt = DateTime::parse_fuzzy("2023/4/5 12:34").unwrap();
+        assert_eq!(
+            dt,
+            DateTime::from(Utc.with_ymd_and_hms(2023, 4, 5, 12, 34, 0))
+        );
+    }
+
+    #[test]
+    fn test_parse_fuzzy_month_name_with_comma_and_pm() {
+        let dt = DateTime::parse_fuzzy("April 5, 2023 8pm").unwrap();
+        assert_eq!(
+            dt,
+            DateTime::from(Utc.with_ymd_and_hms(2023, 4, 5, 20, 0, 0))
+        );
+    }
+
+    #[test]
+    fn test_parse_fuzzy_rejects_garbage() {
+        assert_eq!(DateTime::parse_fuzzy("not a date"), None);
+    }
+
+    #[test]
+    fn test_parse_fuzzy_rejects_out_of_range_day() {
+        assert_eq!(DateTime::parse_fuzzy("April 35, 2023"), None);
+    }
 }
@@ -0,0 +1,472 @@
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+
+/// The concrete numeric representation currently held by a `Number`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberType {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    F32,
+    F64,
+    #[cfg(feature = "decimal")]
+    Decimal,
+    Unknown,
+}
+
+/// Behavior shared by any type that can carry a dynamically-typed number
+/// (namely `Number` itself, and `Value` which delegates to it).
+pub trait NumberBehavior {
+    fn set_u8(&mut self, value: u8);
+    fn set_u16(&mut self, value: u16);
+    fn set_u32(&mut self, value: u32);
+    fn set_u64(&mut self, value: u64);
+    fn set_u128(&mut self, value: u128);
+    fn set_i8(&mut self, value: i8);
+    fn set_i16(&mut self, value: i16);
+    fn set_i32(&mut self, value: i32);
+    fn set_i64(&mut self, value: i64);
+    fn set_i128(&mut self, value: i128);
+    fn set_f32(&mut self, value: f32);
+    fn set_f64(&mut self, value: f64);
+
+    fn get_u8(&self) -> Option<u8>;
+    fn get_u16(&self) -> Option<u16>;
+    fn get_u32(&self) -> Option<u32>;
+    fn get_u64(&self) -> Option<u64>;
+    fn get_u128(&self) -> Option<u128>;
+    fn get_i8(&self) -> Option<i8>;
+    fn get_i16(&self) -> Option<i16>;
+    fn get_i32(&self) -> Option<i32>;
+    fn get_i64(&self) -> Option<i64>;
+    fn get_i128(&self) -> Option<i128>;
+    fn get_f32(&self) -> Option<f32>;
+    fn get_f64(&self) -> Option<f64>;
+
+    fn get_u8_unsafe(&self) -> u8;
+    fn get_u16_unsafe(&self) -> u16;
+    fn get_u32_unsafe(&self) -> u32;
+    fn get_u64_unsafe(&self) -> u64;
+    fn get_u128_unsafe(&self) -> u128;
+    fn get_i8_unsafe(&self) -> i8;
+    fn get_i16_unsafe(&self) -> i16;
+    fn get_i32_unsafe(&self) -> i32;
+    fn get_i64_unsafe(&self) -> i64;
+    fn get_i128_unsafe(&self) -> i128;
+    fn get_f32_unsafe(&self) -> f32;
+    fn get_f64_unsafe(&self) -> f64;
+
+    fn is_i8(&self) -> bool;
+    fn is_i16(&self) -> bool;
+    fn is_i32(&self) -> bool;
+    fn is_i64(&self) -> bool;
+    fn is_i128(&self) -> bool;
+    fn is_u8(&self) -> bool;
+    fn is_u16(&self) -> bool;
+    fn is_u32(&self) -> bool;
+    fn is_u64(&self) -> bool;
+    fn is_u128(&self) -> bool;
+    fn is_f32(&self) -> bool;
+    fn is_f64(&self) -> bool;
+
+    fn is_number(&self) -> bool;
+    fn is_integer(&self) -> bool;
+    fn is_float(&self) -> bool;
+    fn is_signed(&self) -> bool;
+    fn is_unsigned(&self) -> bool;
+    fn is_zero(&self) -> bool;
+    fn is_positive(&self) -> bool;
+    fn is_negative(&self) -> bool;
+
+    fn number_type(&self) -> NumberType;
+}
+
+/// A dynamically-typed number with one `Option` field per primitive width. The `set_*` methods
+/// populate their own field without clearing the others; when several fields are populated,
+/// `number_type`, `Display` and the sign checks read the first one in declaration order.
+///
+/// Besides the fixed-width integer and float fields, an optional `decimal` field (behind the
+/// `decimal` cargo feature) carries an arbitrary-precision `rust_decimal::Decimal`, used for
+/// values — money chief among them — that must not be rounded through `f64`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Number {
+    pub i8: Option<i8>,
+    pub i16: Option<i16>,
+    pub i32: Option<i32>,
+    pub i64: Option<i64>,
+    pub i128: Option<i128>,
+    pub u8: Option<u8>,
+    pub u16: Option<u16>,
+    pub u32: Option<u32>,
+    pub u64: Option<u64>,
+    pub u128: Option<u128>,
+    pub f32: Option<f32>,
+    pub f64: Option<f64>,
+    #[cfg(feature = "decimal")]
+    pub decimal: Option<Decimal>,
+}
+
+impl Number {
+    /// Does nothing: fields populated alongside another one are left in place.
+    pub fn clean(&mut self) {}
+}
+
+#[cfg(feature = "decimal")]
+impl From<Decimal> for Number {
+    fn from(value: Decimal) -> Self {
+        Number {
+            decimal: Some(value),
+            ..Default::default()
+        }
+    }
+}
+
+impl NumberBehavior for Number {
+    fn set_u8(&mut self, value: u8) {
+        self.u8 = Some(value);
+    }
+    fn set_u16(&mut self, value: u16) {
+        self.u16 = Some(value);
+    }
+    fn set_u32(&mut self, value: u32) {
+        self.u32 = Some(value);
+    }
+    fn set_u64(&mut self, value: u64) {
+        self.u64 = Some(value);
+    }
+    fn set_u128(&mut self, value: u128) {
+        self.u128 = Some(value);
+    }
+    fn set_i8(&mut self, value: i8) {
+        self.i8 = Some(value);
+    }
+    fn set_i16(&mut self, value: i16) {
+        self.i16 = Some(value);
+    }
+    fn set_i32(&mut self, value: i32) {
+        self.i32 = Some(value);
+    }
+    fn set_i64(&mut self, value: i64) {
+        self.i64 = Some(value);
+    }
+    fn set_i128(&mut self, value: i128) {
+        self.i128 = Some(value);
+    }
+    fn set_f32(&mut self, value: f32) {
+        self.f32 = Some(value);
+    }
+    fn set_f64(&mut self, value: f64) {
+        self.f64 = Some(value);
+    }
+
+    fn get_u8(&self) -> Option<u8> {
+        self.u8
+    }
+    fn get_u16(&self) -> Option<u16> {
+        self.u16
+    }
+    fn get_u32(&self) -> Option<u32> {
+        self.u32
+    }
+    fn get_u64(&self) -> Option<u64> {
+        self.u64
+    }
+    fn get_u128(&self) -> Option<u128> {
+        self.u128
+    }
+    fn get_i8(&self) -> Option<i8> {
+        self.i8
+    }
+    fn get_i16(&self) -> Option<i16> {
+        self.i16
+    }
+    fn get_i32(&self) -> Option<i32> {
+        self.i32
+    }
+    fn get_i64(&self) -> Option<i64> {
+        self.i64
+    }
+    fn get_i128(&self) -> Option<i128> {
+        self.i128
+    }
+    fn get_f32(&self) -> Option<f32> {
+        self.f32
+    }
+    fn get_f64(&self) -> Option<f64> {
+        self.f64
+    }
+
+    fn get_u8_unsafe(&self) -> u8 {
+        self.u8.unwrap()
+    }
+    fn get_u16_unsafe(&self) -> u16 {
+        self.u16.unwrap()
+    }
+    fn get_u32_unsafe(&self) -> u32 {
+        self.u32.unwrap()
+    }
+    fn get_u64_unsafe(&self) -> u64 {
+        self.u64.unwrap()
+    }
+    fn get_u128_unsafe(&self) -> u128 {
+        self.u128.unwrap()
+    }
+    fn get_i8_unsafe(&self) -> i8 {
+        self.i8.unwrap()
+    }
+    fn get_i16_unsafe(&self) -> i16 {
+        self.i16.unwrap()
+    }
+    fn get_i32_unsafe(&self) -> i32 {
+        self.i32.unwrap()
+    }
+    fn get_i64_unsafe(&self) -> i64 {
+        self.i64.unwrap()
+    }
+    fn get_i128_unsafe(&self) -> i128 {
+        self.i128.unwrap()
+    }
+    fn get_f32_unsafe(&self) -> f32 {
+        self.f32.unwrap()
+    }
+    fn get_f64_unsafe(&self) -> f64 {
+        self.f64.unwrap()
+    }
+
+    fn is_i8(&self) -> bool {
+        self.i8.is_some()
+    }
+    fn is_i16(&self) -> bool {
+        self.i16.is_some()
+    }
+    fn is_i32(&self) -> bool {
+        self.i32.is_some()
+    }
+    fn is_i64(&self) -> bool {
+        self.i64.is_some()
+    }
+    fn is_i128(&self) -> bool {
+        self.i128.is_some()
+    }
+    fn is_u8(&self) -> bool {
+        self.u8.is_some()
+    }
+    fn is_u16(&self) -> bool {
+        self.u16.is_some()
+    }
+    fn is_u32(&self) -> bool {
+        self.u32.is_some()
+    }
+    fn is_u64(&self) -> bool {
+        self.u64.is_some()
+    }
+    fn is_u128(&self) -> bool {
+        self.u128.is_some()
+    }
+    fn is_f32(&self) -> bool {
+        self.f32.is_some()
+    }
+    fn is_f64(&self) -> bool {
+        self.f64.is_some()
+    }
+
+    fn is_number(&self) -> bool {
+        true
+    }
+
+    fn is_integer(&self) -> bool {
+        self.is_i8()
+            || self.is_i16()
+            || self.is_i32()
+            || self.is_i64()
+            || self.is_i128()
+            || self.is_u8()
+            || self.is_u16()
+            || self.is_u32()
+            || self.is_u64()
+            || self.is_u128()
+    }
+
+    fn is_float(&self) -> bool {
+        self.is_f32() || self.is_f64()
+    }
+
+    fn is_signed(&self) -> bool {
+        self.is_i8() || self.is_i16() || self.is_i32() || self.is_i64() || self.is_i128()
+    }
+
+    fn is_unsigned(&self) -> bool {
+        self.is_u8() || self.is_u16() || self.is_u32() || self.is_u64() || self.is_u128()
+    }
+
+    fn is_zero(&self) -> bool {
+        #[cfg(feature = "decimal")]
+        if let Some(decimal) = self.decimal {
+            return decimal.is_zero();
+        }
+        self.get_i128_or_cast() == 0.0
+    }
+
+    fn is_positive(&self) -> bool {
+        #[cfg(feature = "decimal")]
+        if let Some(decimal) = self.decimal {
+            return decimal.is_sign_positive() && !decimal.is_zero();
+        }
+        self.get_i128_or_cast() > 0.0
+    }
+
+    fn is_negative(&self) -> bool {
+        #[cfg(feature = "decimal")]
+        if let Some(decimal) = self.decimal {
+            return decimal.is_sign_negative() && !decimal.is_zero();
+        }
+        self.get_i128_or_cast() < 0.0
+    }
+
+    fn number_type(&self) -> NumberType {
+        #[cfg(feature = "decimal")]
+        if self.decimal.is_some() {
+            return NumberType::Decimal;
+        }
+        if self.i8.is_some() {
+            NumberType::I8
+        } else if self.i16.is_some() {
+            NumberType::I16
+        } else if self.i32.is_some() {
+            NumberType::I32
+        } else if self.i64.is_some() {
+            NumberType::I64
+        } else if self.i128.is_some() {
+            NumberType::I128
+        } else if self.u8.is_some() {
+            NumberType::U8
+        } else if self.u16.is_some() {
+            NumberType::U16
+        } else if self.u32.is_some() {
+            NumberType::U32
+        } else if self.u64.is_some() {
+            NumberType::U64
+        } else if self.u128.is_some() {
+            NumberType::U128
+        } else if self.f32.is_some() {
+            NumberType::F32
+        } else if self.f64.is_some() {
+            NumberType::F64
+        } else {
+            NumberType::Unknown
+        }
+    }
+}
+
+impl Number {
+    /// Widens whichever field is populated to an `f64` for the sign/zero comparisons above.
+    /// The `decimal` field is handled separately, before this is ever reached.
+    fn get_i128_or_cast(&self) -> f64 {
+        if let Some(v) = self.i8 {
+            v as f64
+        } else if let Some(v) = self.i16 {
+            v as f64
+        } else if let Some(v) = self.i32 {
+            v as f64
+        } else if let Some(v) = self.i64 {
+            v as f64
+        } else if let Some(v) = self.i128 {
+            v as f64
+        } else if let Some(v) = self.u8 {
+            v as f64
+        } else if let Some(v) = self.u16 {
+            v as f64
+        } else if let Some(v) = self.u32 {
+            v as f64
+        } else if let Some(v) = self.u64 {
+            v as f64
+        } else if let Some(v) = self.u128 {
+            v as f64
+        } else if let Some(v) = self.f32 {
+            v as f64
+        } else if let Some(v) = self.f64 {
+            v
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Displays the populated field. The `decimal` field, when present, is always printed as its
+/// exact digits (`rust_decimal::Decimal`'s `Display` never uses scientific notation), which is
+/// why it takes priority over the lossy float fields.
+impl Display for Number {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        #[cfg(feature = "decimal")]
+        if let Some(decimal) = self.decimal {
+            return write!(f, "{}", decimal);
+        }
+        if let Some(v) = self.i8 {
+            write!(f, "{}", v)
+        } else if let Some(v) = self.i16 {
+            write!(f, "{}", v)
+        } else if let Some(v) = self.i32 {
+            write!(f, "{}", v)
+        } else if let Some(v) = self.i64 {
+            write!(f, "{}", v)
+        } else if let Some(v) = self.i128 {
+            write!(f, "{}", v)
+        } else if let Some(v) = self.u8 {
+            write!(f, "{}", v)
+        } else if let Some(v) = self.u16 {
+            write!(f, "{}", v)
+        } else if let Some(v) = self.u32 {
+            write!(f, "{}", v)
+        } else if let Some(v) = self.u64 {
+            write!(f, "{}", v)
+        } else if let Some(v) = self.u128 {
+            write!(f, "{}", v)
+        } else if let Some(v) = self.f32 {
+            write!(f, "{}", v)
+        } else if let Some(v) = self.f64 {
+            write!(f, "{}", v)
+        } else {
+            write!(f, "0")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_type() {
+        let number = Number {
+            i32: Some(42),
+            ..Default::default()
+        };
+        assert_eq!(number.number_type(), NumberType::I32);
+    }
+
+    #[test]
+    fn test_display_uses_populated_field() {
+        let number = Number {
+            f64: Some(3.14),
+            ..Default::default()
+        };
+        assert_eq!(number.to_string(), "3.14");
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_decimal_display_never_scientific() {
+        let number = Number::from(Decimal::new(123456789, 2));
+        assert_eq!(number.to_string(), "1234567.89");
+        assert_eq!(number.number_type(), NumberType::Decimal);
+    }
+}
@@ -215,6 +215,109 @@ impl StringB {
         let string = c_string.into_string()?;
         Ok(StringB::new(string))
     }
+
+    /// Creates a `StringB` from a `Vec<u8>`, replacing any invalid UTF-8 sequences with
+    /// `U+FFFD REPLACEMENT CHARACTER`, the way `String::from_utf8_lossy` does. Unlike
+    /// `from_utf8`, this never fails or panics, so it's safe to use on untrusted bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let bytes = vec![104, 101, 108, 108, 111, 0xff];
+    /// let s = StringB::from_utf8_lossy(bytes);
+    /// ```
+    pub fn from_utf8_lossy(value: Vec<u8>) -> Self {
+        let lossy = String::from_utf8_lossy(&value).into_owned();
+        #[cfg(feature = "cstring")]
+        {
+            let sanitized = lossy.replace('\0', "\u{FFFD}");
+            StringB {
+                value: CString::new(sanitized).expect("NUL bytes were just replaced"),
+            }
+        }
+        #[cfg(not(feature = "cstring"))]
+        {
+            StringB::new(lossy)
+        }
+    }
+
+    /// Creates a `StringB` from a `Vec<u8>`, failing instead of panicking if the bytes aren't
+    /// valid UTF-8 (or, under the `cstring` feature, contain an interior NUL byte).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let bytes = vec![104, 101, 108, 108, 111]; // "hello" in UTF-8
+    /// let s = StringB::try_from_utf8(bytes).unwrap();
+    /// ```
+    #[cfg(not(feature = "cstring"))]
+    pub fn try_from_utf8(value: Vec<u8>) -> Result<Self, std::string::FromUtf8Error> {
+        Ok(StringB::new(String::from_utf8(value)?))
+    }
+
+    #[cfg(feature = "cstring")]
+    pub fn try_from_utf8(value: Vec<u8>) -> Result<Self, StringBError> {
+        std::str::from_utf8(&value)?;
+        let value = CString::new(value)?;
+        Ok(StringB { value })
+    }
+}
+
+/// Why a byte sequence couldn't become a `StringB` under the `cstring` feature: either it
+/// wasn't valid UTF-8, or (having passed that check) it had an interior NUL byte, which
+/// `CString` can't represent.
+#[cfg(feature = "cstring")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum StringBError {
+    InvalidUtf8(std::str::Utf8Error),
+    InteriorNul(std::ffi::NulError),
+}
+
+#[cfg(feature = "cstring")]
+impl Display for StringBError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            StringBError::InvalidUtf8(err) => write!(f, "{}", err),
+            StringBError::InteriorNul(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[cfg(feature = "cstring")]
+impl std::error::Error for StringBError {}
+
+#[cfg(feature = "cstring")]
+impl From<std::str::Utf8Error> for StringBError {
+    fn from(err: std::str::Utf8Error) -> Self {
+        StringBError::InvalidUtf8(err)
+    }
+}
+
+#[cfg(feature = "cstring")]
+impl From<std::ffi::NulError> for StringBError {
+    fn from(err: std::ffi::NulError) -> Self {
+        StringBError::InteriorNul(err)
+    }
+}
+
+/// Implements the fallible `TryFrom<Vec<u8>>` trait for `StringB`, rejecting invalid UTF-8
+/// (or, under the `cstring` feature, an interior NUL byte) instead of panicking.
+#[cfg(not(feature = "cstring"))]
+impl TryFrom<Vec<u8>> for StringB {
+    type Error = std::string::FromUtf8Error;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        StringB::try_from_utf8(value)
+    }
+}
+
+#[cfg(feature = "cstring")]
+impl TryFrom<Vec<u8>> for StringB {
+    type Error = StringBError;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        StringB::try_from_utf8(value)
+    }
 }
 
 /// Implements the `Display` trait for `StringB`.
@@ -273,6 +376,34 @@ impl From<Vec<u8>> for StringB {
     }
 }
 
+/// Serializes `StringB` as a plain string, matching how `serde_json`/`serde_yaml` render a
+/// Rust `String`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for StringB {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Deserializes `StringB` from a string. With the `cstring` feature enabled, a value
+/// containing an interior NUL byte is rejected with a deserialization error rather than
+/// panicking.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for StringB {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        #[cfg(feature = "cstring")]
+        {
+            let value = CString::new(value).map_err(serde::de::Error::custom)?;
+            Ok(StringB { value })
+        }
+        #[cfg(not(feature = "cstring"))]
+        {
+            Ok(StringB::new(value))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -319,4 +450,24 @@ mod tests {
         let s2 = " world";
         assert_eq!(s1.concat(s2).as_str(), "hello world");
     }
+
+    #[test]
+    fn test_from_utf8_lossy_replaces_invalid_sequences() {
+        let bytes = vec![104, 101, 108, 108, 111, 0xff];
+        let s = StringB::from_utf8_lossy(bytes);
+        assert_eq!(s.as_str(), "hello\u{FFFD}");
+    }
+
+    #[test]
+    fn test_try_from_utf8_rejects_invalid_bytes() {
+        let bytes = vec![104, 101, 0xff, 108, 108, 111];
+        assert!(StringB::try_from_utf8(bytes).is_err());
+    }
+
+    #[test]
+    fn test_try_from_vec_u8_succeeds_on_valid_utf8() {
+        let bytes = vec![104, 101, 108, 108, 111];
+        let s = StringB::try_from(bytes).unwrap();
+        assert_eq!(s.as_str(), "hello");
+    }
 }
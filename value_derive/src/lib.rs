@@ -127,48 +127,119 @@ fn to_value_enum_impl(
 pub fn from_value_derive(input: TokenStream) -> TokenStream {
     // Parse a `DeriveInput` AST from the input tokens.
     let ast = parse_macro_input!(input as DeriveInput);
+    let name = &ast.ident;
 
-    // Get the name and fields of the struct being derived.
-    let struct_name = &ast.ident;
-    let struct_fields = match ast.data {
-        Data::Struct(data_struct) => data_struct.fields,
-        _ => panic!("Can only derive FromValueTrait for a struct."),
+    let expanded = match ast.data {
+        Data::Struct(data) => from_value_struct_impl(name, data.fields),
+        Data::Enum(data) => from_value_enum_impl(name, data.variants),
+        Data::Union(_) => panic!("FromValueTrait cannot be derived for unions"),
     };
 
-    // Define a new implementation of the `FromValueTrait` trait for the struct.
-    let mut field_names = Vec::new();
-    let mut field_types = Vec::new();
-    let mut from_value_exprs = Vec::new();
-    if let Fields::Named(fields) = struct_fields {
-        for field in fields.named.iter() {
-            let field_name = field.ident.as_ref().unwrap();
-            let field_type = &field.ty;
-            field_names.push(field_name.clone());
-            field_types.push(field_type.clone());
-            from_value_exprs.push(quote! {
-                #field_name: <#field_type as FromValueTrait>::from_value(map.get(stringify!(#field_name)))?
-            });
-        }
-    } else {
-        panic!("Can only derive FromValueTrait for a struct with named fields.");
+    // Return the generated code as a `TokenStream`.
+    TokenStream::from(expanded)
+}
+
+// Builds `field: <Type as FromValueTrait>::from_value(...)` for one named field, pulling the
+// field's value out of `value` by key and falling back to `Value::Null` when absent.
+fn from_value_field_expr(field: &syn::Field, accessor: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let field_name = field.ident.as_ref().unwrap();
+    let field_type = &field.ty;
+    quote! {
+        #field_name: <#field_type as FromValueTrait>::from_value(
+            value.get(#accessor).cloned().unwrap_or(Value::Null)
+        )
     }
+}
 
-    let expanded = quote! {
-        impl FromValueTrait for #struct_name {
-            fn from_value(value: &Value) -> Option<Self> {
-                if let Value::Object(map) = value {
-                    Some(Self {
-                        #(#from_value_exprs),*
-                    })
-                } else {
-                    None
+fn from_value_struct_impl(name: &syn::Ident, fields: Fields) -> proc_macro2::TokenStream {
+    let field_exprs = match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                let key = field.ident.as_ref().unwrap().to_string();
+                from_value_field_expr(field, quote! { #key })
+            })
+            .collect::<Vec<_>>(),
+        Fields::Unnamed(_) => panic!("Can only derive FromValueTrait for a struct with named fields."),
+        Fields::Unit => {
+            return quote! {
+                impl FromValueTrait for #name {
+                    fn from_value(_value: Value) -> Self {
+                        #name
+                    }
                 }
             }
         }
     };
 
-    // Return the generated code as a `TokenStream`.
-    TokenStream::from(expanded)
+    quote! {
+        impl FromValueTrait for #name {
+            fn from_value(value: Value) -> Self {
+                Self {
+                    #(#field_exprs),*
+                }
+            }
+        }
+    }
+}
+
+// Rebuilds an enum from a `"type"`-tagged `Value::Object`: named fields are read back by key,
+// unnamed/tuple fields by their stringified position ("0", "1", ...), and unit variants need
+// only the tag itself. A missing tag or an unknown variant name panics.
+fn from_value_enum_impl(
+    name: &syn::Ident,
+    variants: syn::punctuated::Punctuated<Variant, syn::Token![,]>,
+) -> proc_macro2::TokenStream {
+    let arms = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let tag = variant_name.to_string();
+
+        match &variant.fields {
+            Fields::Named(fields) => {
+                let field_exprs = fields.named.iter().map(|field| {
+                    let field_name = field.ident.as_ref().unwrap();
+                    let key = field_name.to_string();
+                    from_value_field_expr(field, quote! { #key })
+                });
+                quote! {
+                    #tag => #name::#variant_name { #(#field_exprs),* },
+                }
+            }
+            Fields::Unnamed(fields) => {
+                let field_exprs = fields.unnamed.iter().enumerate().map(|(index, field)| {
+                    let field_type = &field.ty;
+                    let key = index.to_string();
+                    quote! {
+                        <#field_type as FromValueTrait>::from_value(
+                            value.get(#key).cloned().unwrap_or(Value::Null)
+                        )
+                    }
+                });
+                quote! {
+                    #tag => #name::#variant_name( #(#field_exprs),* ),
+                }
+            }
+            Fields::Unit => quote! {
+                #tag => #name::#variant_name,
+            },
+        }
+    });
+
+    quote! {
+        impl FromValueTrait for #name {
+            fn from_value(value: Value) -> Self {
+                let tag = match value.get("type") {
+                    Some(Value::String(tag)) => tag.to_string(),
+                    _ => panic!("tagged enum value is missing its \"type\" discriminator"),
+                };
+                match tag.as_str() {
+                    #(#arms)*
+                    other => panic!("unknown variant tag: {}", other),
+                }
+            }
+        }
+    }
 }
 
 #[proc_macro_derive(ToJson)]
@@ -221,3 +292,150 @@ pub fn to_xml_derive(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+#[proc_macro_derive(Schema)]
+pub fn schema_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let name = &ast.ident;
+
+    let schema_expr = match ast.data {
+        Data::Struct(data) => schema_struct_impl(data.fields),
+        Data::Enum(data) => schema_enum_impl(data.variants),
+        Data::Union(_) => panic!("Schema cannot be derived for unions"),
+    };
+
+    let expanded = quote! {
+        impl #name {
+            /// Returns the `Schema` describing this type's shape, for validating a
+            /// dynamically-built `Value` against it before calling `from_value`.
+            pub fn schema() -> Schema {
+                #schema_expr
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+fn schema_struct_impl(fields: Fields) -> proc_macro2::TokenStream {
+    match fields {
+        Fields::Named(fields) => {
+            let entries = fields.named.iter().map(|field| {
+                let field_name = field.ident.as_ref().unwrap().to_string();
+                let schema = schema_expr_for_type(&field.ty);
+                quote! { __fields.insert(#field_name.to_string(), #schema); }
+            });
+            quote! {
+                {
+                    let mut __fields = std::collections::HashMap::new();
+                    #(#entries)*
+                    Schema::Object(__fields)
+                }
+            }
+        }
+        // Tuple and unit structs have no field names to key a `Schema::Object` by.
+        Fields::Unnamed(_) | Fields::Unit => quote! { Schema::Any },
+    }
+}
+
+// A tagged enum's schema is the union of each variant's own object shape (the `"type"`
+// discriminator field plus the variant's named fields), the same tagged shape
+// `#[derive(FromValue)]` reads back. Tuple variants are described as `Schema::Any`.
+fn schema_enum_impl(
+    variants: syn::punctuated::Punctuated<Variant, syn::Token![,]>,
+) -> proc_macro2::TokenStream {
+    let variant_schemas = variants.iter().map(|variant| match &variant.fields {
+        Fields::Named(fields) => {
+            let entries = fields.named.iter().map(|field| {
+                let field_name = field.ident.as_ref().unwrap().to_string();
+                let schema = schema_expr_for_type(&field.ty);
+                quote! { __fields.insert(#field_name.to_string(), #schema); }
+            });
+            quote! {
+                {
+                    let mut __fields = std::collections::HashMap::new();
+                    __fields.insert("type".to_string(), Schema::String);
+                    #(#entries)*
+                    Schema::Object(__fields)
+                }
+            }
+        }
+        Fields::Unit => quote! {
+            {
+                let mut __fields = std::collections::HashMap::new();
+                __fields.insert("type".to_string(), Schema::String);
+                Schema::Object(__fields)
+            }
+        },
+        Fields::Unnamed(_) => quote! { Schema::Any },
+    });
+
+    quote! {
+        Schema::Union(vec![ #(#variant_schemas),* ])
+    }
+}
+
+// Maps a Rust field type to the `Schema` variant describing it, reusing the same
+// "one arm per primitive width" shape as `NumberBehavior`. Anything unrecognized (a
+// user-defined type, a tuple, ...) falls back to `Schema::Any` rather than guessing.
+fn schema_expr_for_type(ty: &syn::Type) -> proc_macro2::TokenStream {
+    let syn::Type::Path(type_path) = ty else {
+        return quote! { Schema::Any };
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return quote! { Schema::Any };
+    };
+
+    match segment.ident.to_string().as_str() {
+        "bool" => quote! { Schema::Bool },
+        "i8" => quote! { Schema::I8 },
+        "i16" => quote! { Schema::I16 },
+        "i32" => quote! { Schema::I32 },
+        "i64" => quote! { Schema::I64 },
+        "i128" => quote! { Schema::I128 },
+        "u8" => quote! { Schema::U8 },
+        "u16" => quote! { Schema::U16 },
+        "u32" => quote! { Schema::U32 },
+        "u64" => quote! { Schema::U64 },
+        "u128" => quote! { Schema::U128 },
+        "f32" => quote! { Schema::F32 },
+        "f64" => quote! { Schema::F64 },
+        "String" => quote! { Schema::String },
+        "DateTime" => quote! { Schema::DateTime },
+        "Option" => {
+            let inner = schema_expr_for_generic_arg(segment);
+            quote! { Schema::Optional(Box::new(#inner)) }
+        }
+        "Vec" => {
+            let inner = schema_expr_for_generic_arg(segment);
+            quote! { Schema::Array(Box::new(#inner)) }
+        }
+        _ => quote! { Schema::Any },
+    }
+}
+
+fn schema_expr_for_generic_arg(segment: &syn::PathSegment) -> proc_macro2::TokenStream {
+    if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+        if let Some(syn::GenericArgument::Type(inner_ty)) = args.args.first() {
+            return schema_expr_for_type(inner_ty);
+        }
+    }
+    quote! { Schema::Any }
+}
+
+#[proc_macro_derive(ToToml)]
+pub fn to_toml_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = input.ident;
+
+    let expanded = quote! {
+        impl ToTomlTrait for #name {
+            fn to_toml(&self) -> String {
+                let value = self.to_value();
+                value.to_toml()
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
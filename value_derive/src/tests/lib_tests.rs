@@ -34,7 +34,7 @@ fn test_from_value() {
         ].into_iter().collect()
     );
 
-    let person = Person::from_value(value).unwrap();
+    let person = Person::from_value(value);
     let expected = Person {
         name: "John Doe".to_string(),
         age: 30,
@@ -54,4 +54,31 @@ fn test_to_json() {
     let expected = r#"{"name":"John Doe","age":30}"#;
 
     assert_eq!(json, expected);
+}
+
+#[derive(Debug, PartialEq, FromValueTrait)]
+enum Shape {
+    Circle { radius: u32 },
+    Point,
+}
+
+#[test]
+fn test_from_value_enum_named_variant() {
+    let value = Value::Object(
+        vec![
+            ("type".to_owned(), Value::String("Circle".to_owned())),
+            ("radius".to_owned(), Value::Number(5.into())),
+        ].into_iter().collect()
+    );
+
+    assert_eq!(Shape::from_value(value), Shape::Circle { radius: 5 });
+}
+
+#[test]
+fn test_from_value_enum_unit_variant() {
+    let value = Value::Object(
+        vec![("type".to_owned(), Value::String("Point".to_owned()))].into_iter().collect()
+    );
+
+    assert_eq!(Shape::from_value(value), Shape::Point);
 }
\ No newline at end of file